@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashSet;
+use leptos::*;
+
+use crate::{
+	toaster::expect_toaster,
+	toast::{ToastBuilder, ToastLevel},
+};
+
+/// Wraps its children in an `ErrorBoundary` that shows a toast for every error it
+/// captures. Each distinct error is toasted once until the boundary recovers.
+///
+/// Takes an optional `level` (`ToastLevel::Error` by default), an optional `message`
+/// that replaces the error's own message, and an optional `fallback` view that is
+/// rendered in place of the children while there are errors.
+///
+/// # Examples
+/// ```
+/// use leptos::*;
+/// use leptoaster::*;
+///
+/// #[component]
+/// fn Component() -> impl IntoView {
+///     view! {
+///         <ToastErrorBoundary message="Something went wrong.">
+///             <MyFallibleComponent />
+///         </ToastErrorBoundary>
+///     }
+/// }
+/// ```
+#[component]
+pub fn ToastErrorBoundary(
+	#[prop(optional)]
+	level: Option<ToastLevel>,
+
+	#[prop(optional, into)]
+	message: Option<String>,
+
+	#[prop(optional, into)]
+	fallback: ViewFn,
+
+	children: Children,
+) -> impl IntoView {
+	let toaster = expect_toaster();
+	let level = level.unwrap_or(ToastLevel::Error);
+
+	let message = store_value(message);
+	let fallback = store_value(fallback);
+	let toasted = store_value(HashSet::<String>::new());
+
+	view! {
+		<ErrorBoundary
+			fallback=move |errors| {
+				let toaster = toaster.clone();
+				let level = level.clone();
+
+				create_effect(move |_| {
+					for (_, error) in errors.get() {
+						let error_message = error.to_string();
+
+						if toasted.with_value(|toasted| toasted.contains(&error_message)) {
+							continue;
+						}
+
+						toasted.update_value(|toasted| {
+							toasted.insert(error_message.clone());
+						});
+
+						let text = message
+							.get_value()
+							.unwrap_or(error_message);
+
+						untrack(|| toaster.toast(
+							ToastBuilder::new(&text)
+								.with_level(level.clone())
+						));
+					}
+				});
+
+				on_cleanup(move || toasted.update_value(HashSet::clear));
+
+				fallback.with_value(ViewFn::run)
+			}
+		>
+			{children()}
+		</ErrorBoundary>
+	}
+}
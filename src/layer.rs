@@ -0,0 +1,129 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt;
+
+use tracing::{
+	Event,
+	Level,
+	Subscriber,
+	field::{Field, Visit},
+};
+
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::{
+	toaster::{context::ToasterContext, set_global_toaster, global_toaster},
+	toast::{ToastBuilder, ToastLevel},
+};
+
+type LevelMapping = dyn Fn(&Level) -> Option<ToastLevel> + Send + Sync;
+
+/// A `tracing_subscriber` layer that shows `tracing` events as toasts. By default,
+/// `WARN` events are shown as `warn` toasts, `ERROR` events are shown as `error`
+/// toasts, and all other events are ignored.
+///
+/// The toaster context is not thread-safe, so the layer shows events in the global
+/// toaster set with `set_global_toaster`, which only exists on the thread that set
+/// it. This is the only thread in the browser.
+///
+/// # Examples
+/// ```
+/// #[component]
+/// fn App() -> impl IntoView {
+///     provide_toaster();
+///
+///     tracing_subscriber::registry()
+///         .with(ToasterLayer::new(expect_toaster()))
+///         .init();
+/// }
+/// ```
+pub struct ToasterLayer {
+	level_mapping: Box<LevelMapping>,
+}
+
+impl ToasterLayer {
+	/// Constructs a new layer that shows events as toasts in the supplied toaster,
+	/// which becomes the global toaster returned by `global_toaster`.
+	#[must_use]
+	pub fn new(toaster: ToasterContext) -> Self {
+		set_global_toaster(toaster);
+
+		ToasterLayer {
+			level_mapping: Box::new(get_default_toast_level),
+		}
+	}
+
+	/// Sets the function that maps the level of an event to the level of its toast.
+	/// Events mapped to `None` are not shown.
+	///
+	/// # Examples
+	/// ```
+	/// ToasterLayer::new(expect_toaster())
+	///     .with_level_mapping(|level| match *level {
+	///         Level::ERROR => Some(ToastLevel::Error),
+	///         Level::WARN => Some(ToastLevel::Warn),
+	///         Level::INFO => Some(ToastLevel::Info),
+	///         _ => None,
+	///     }); // also shows `INFO` events as `info` toasts.
+	/// ```
+	#[must_use]
+	pub fn with_level_mapping(
+		mut self,
+		level_mapping: impl Fn(&Level) -> Option<ToastLevel> + Send + Sync + 'static,
+	) -> Self {
+		self.level_mapping = Box::new(level_mapping);
+		self
+	}
+}
+
+impl<S: Subscriber> Layer<S> for ToasterLayer {
+	fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+		let Some(level) = (self.level_mapping)(event.metadata().level()) else {
+			return;
+		};
+
+		let Some(toaster) = global_toaster() else {
+			return;
+		};
+
+		let mut visitor = MessageVisitor::default();
+		event.record(&mut visitor);
+
+		toaster.toast(
+			ToastBuilder::new(&visitor.message)
+				.with_level(level)
+		);
+	}
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+	message: String,
+}
+
+impl Visit for MessageVisitor {
+	fn record_str(&mut self, field: &Field, value: &str) {
+		if field.name() == "message" {
+			self.message = value.into();
+		}
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		if field.name() == "message" {
+			self.message = format!("{value:?}");
+		}
+	}
+}
+
+fn get_default_toast_level(level: &Level) -> Option<ToastLevel> {
+	match *level {
+		Level::ERROR => Some(ToastLevel::Error),
+		Level::WARN => Some(ToastLevel::Warn),
+		_ => None,
+	}
+}
@@ -7,17 +7,43 @@
 
 mod toaster;
 mod toast;
+mod error_boundary;
+
+#[cfg(feature = "tracing")]
+mod layer;
 
 pub use crate::{
 	toaster::{
 		Toaster,
+		ToastLabels,
+		ToastEvent,
+		ToasterConfig,
+		OverflowPolicy,
 		provide_toaster,
+		provide_toaster_with_config,
 		expect_toaster,
+		set_global_toaster,
+		global_toaster,
+		use_toast_labels,
 	},
 
 	toast::{
 		ToastBuilder,
+		ToastData,
+		ToastId,
 		ToastLevel,
 		ToastPosition,
+		ToastAnimation,
+		SlideOrigin,
+		ProgressDirection,
+		ProgressPosition,
+		PositionFallback,
+		ToastAlign,
+		use_toast_timer,
 	},
+
+	error_boundary::ToastErrorBoundary,
 };
+
+#[cfg(feature = "tracing")]
+pub use crate::layer::ToasterLayer;
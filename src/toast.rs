@@ -8,172 +8,1402 @@
 mod data;
 mod builder;
 
+use std::{
+	time::Duration,
+	future::Future,
+	rc::Rc,
+	cell::Cell,
+};
 use leptos::*;
+use leptos::wasm_bindgen::JsCast;
 use gloo_timers::future::TimeoutFuture;
-use crate::toaster::expect_toaster;
+use crate::toaster::{expect_toaster, use_toast_labels};
+
+const TIMER_TICK: u32 = 50;
+const SWIPE_THRESHOLD: i32 = 80;
+const SWIPE_CLICK_TOLERANCE: i32 = 5;
 
 pub use crate::toast::data::{
 	ToastData,
 	ToastId,
 	ToastLevel,
 	ToastPosition,
+	ToastAnimation,
+	SlideOrigin,
+	ProgressDirection,
+	ProgressPosition,
+	PositionFallback,
+	ToastAlign,
 };
 
 /// A toast element with the supplied alert style.
+///
+/// Clickable toasts can be focused and activated with the `Enter` or `Space` key,
+/// just like clicking them.
+///
+/// At most `max_enter_animations` toasts animate in at the same time. Any toast
+/// beyond that limit appears in place without an enter animation.
+///
+/// When `show_icons` is set, an icon matching the level of the toast is rendered
+/// before the message.
+///
+/// When `show_close_button` is set, a close button that always dismisses the toast
+/// is rendered at its end, regardless of whether the toast is dismissable.
+///
+/// When `pause_on_hover` is set, the expiry timer of the toast is paused while the
+/// pointer is over the toast.
+///
+/// The enter and exit animations of the toast take `animation_duration` milliseconds.
+/// When the user prefers reduced motion, the toast appears and is removed without
+/// animating.
+///
+/// When `swipe_to_dismiss` is set, dismissable toasts can be dragged sideways and are
+/// dismissed once dragged far enough.
+///
+/// When `rtl` is set, the toast slides in and out from the opposite side of the screen.
+///
+/// When `keyboard_focus` is supplied, the toast takes part in its container's roving
+/// tab index, being focusable with the tab key only while it holds the focus.
+///
+/// When `expanded_groups` is supplied and `group_count` is above one, the toast leads
+/// its collapsed group and shows a toggle with the number of toasts in the group that
+/// adds the group to or removes it from the expanded groups.
+///
+/// When `stack_expanded` is supplied, tapping the toast with a touch or pen pointer
+/// toggles whether its stack is expanded. A tap on a collapsed stack only expands it.
 #[component]
-pub fn Toast(toast: ToastData) -> impl IntoView {
-	let animation_duration = 200;
+pub fn Toast(
+	toast: ToastData,
+	max_enter_animations: Option<u32>,
+	animation_duration: u32,
+	show_icons: bool,
+	show_close_button: bool,
+	pause_on_hover: bool,
+	swipe_to_dismiss: bool,
+	rtl: bool,
+	keyboard_focus: Option<RwSignal<Option<ToastId>>>,
+	group_count: Signal<usize>,
+	expanded_groups: Option<RwSignal<Vec<String>>>,
+	stack_expanded: Option<RwSignal<bool>>,
+) -> impl IntoView {
+	let toaster = expect_toaster();
+	let reduced_motion = prefers_reduced_motion();
+	let animate_enter = !reduced_motion && toaster.start_enter_animation(max_enter_animations);
+
+	let toaster_paused = toaster.is_paused();
+	let paused = Signal::derive(move || toast.pause_signal.get() || toaster_paused.get());
 
-	let slide_in_animation_name = get_slide_in_animation_name(&toast.position);
-	let slide_out_animation_name = get_slide_out_animation_name(&toast.position);
+	if animate_enter {
+		spawn_local(async move {
+			TimeoutFuture::new(animation_duration).await;
+			toaster.finish_enter_animation();
+		});
+	}
 
-	let (animation_name, set_animation_name) = create_signal(slide_in_animation_name);
+	if let Some(on_show) = toast.on_show.clone() {
+		let shown = toast.shown;
 
-	let (background_color, border_color, text_color) = get_colors(&toast.level);
-	let (initial_left, initial_right) = get_initial_positions(&toast.position);
+		create_effect(move |_| {
+			if !shown.get_untracked() {
+				shown.set(true);
+				untrack(|| on_show(toast.id));
+			}
+		});
+	}
+
+	let physical_position = get_physical_position(&toast.position, rtl);
+	let slide_origin = toast.slide_from.unwrap_or_else(|| get_slide_origin(&physical_position));
+
+	let enter_animation_name = match animate_enter {
+		true => get_enter_animation_name(toast.animation, slide_origin),
+		false => "none",
+	};
+
+	let exit_animation_name = get_exit_animation_name(toast.animation, slide_origin);
+
+	let (animation_name, set_animation_name) = create_signal(enter_animation_name);
+
+	let content = create_content_memo(&toast);
+
+	let updated = create_memo(move |previous: Option<&bool>| {
+		content.track();
+		previous.is_some()
+	});
+
+	let level = create_memo(move |_| content.with(|content| content.level.clone()));
+
+	let colors = create_memo(move |_| get_colors(&level()));
+
+	let background_color = move || colors.with(|colors| colors.0.clone());
+	let border_color = move || colors.with(|colors| colors.1.clone());
+	let text_color = move || colors.with(|colors| colors.2.clone());
+	let icon_path = move || get_icon_path(&level());
+
+	let (initial_left, initial_right) = match animate_enter {
+		true => get_initial_positions(toast.animation, slide_origin, &physical_position),
+		false => get_resting_positions(&physical_position),
+	};
+
+	let labels = use_toast_labels();
+	let dismiss_label = labels.dismiss;
+	let close_label = dismiss_label.clone();
+	let minimize_label = labels.minimize;
+	let show_more_label = labels.show_more;
+	let show_less_label = labels.show_less;
+	let group_count_label = store_value(labels.group_count);
+
+	let message_length = toast.message.chars().count();
+	let typewriter = toast.typewriter.filter(|_| !reduced_motion);
+	let reveal_duration = typewriter.map_or(0, |speed| speed.saturating_mul(message_length as u32));
+
+	let (revealed, set_revealed) = create_signal(match typewriter {
+		Some(_) => 0,
+		None => message_length,
+	});
+
+	if let Some(speed) = typewriter {
+		spawn_local(async move {
+			for length in 1..=message_length {
+				TimeoutFuture::new(speed).await;
+				set_revealed(length);
+			}
+		});
+	}
+
+	let elapsed = start_toast_timer(&toast, reveal_duration);
+
+	let mounted_at = now();
+	let paused_time = store_value(0.0);
+	let paused_since = store_value(None::<f64>);
+
+	create_effect(move |_| {
+		match (paused.get(), paused_since.get_value()) {
+			(true, None) => paused_since.set_value(Some(now())),
+
+			(false, Some(since)) => {
+				paused_time.update_value(|paused_time| *paused_time += now() - since);
+				paused_since.set_value(None);
+			},
+
+			_ => {},
+		}
+	});
+
+	let on_removed = toast.on_removed;
+	let progress_content = toast.progress_content;
+
+	create_resource(move || toast.clear_signal.get(), move |clear| {
+		let on_removed = on_removed.clone();
+
+		async move {
+			if clear {
+				let paused = paused_time.get_value() + paused_since.get_value().map_or(0.0, |since| now() - since);
+				let dwell_time = Duration::from_secs_f64((now() - mounted_at - paused).max(0.0) / 1_000.0);
+
+				if toast.exit_animation && !reduced_motion {
+					set_animation_name(exit_animation_name);
+					TimeoutFuture::new(animation_duration).await;
+				}
+
+				if let Some(on_removed) = on_removed {
+					on_removed(toast.id, dwell_time);
+				}
+
+				expect_toaster().remove(toast.id);
+			}
+		}
+	});
+
+	let title = toast.title.clone();
+	let actions = toast.actions.clone();
 
-	create_resource(|| (), move |()| async move {
-		let Some(expiry) = toast.expiry else {
+	let message_ref = create_node_ref::<html::Span>();
+	let (expanded, set_expanded) = create_signal(false);
+	let (overflowing, set_overflowing) = create_signal(false);
+	let clamped = move || toast.max_lines.is_some() && !expanded();
+
+	create_effect(move |_| {
+		let (Some(max_lines), Some(span)) = (toast.max_lines, message_ref.get()) else {
 			return;
 		};
 
-		TimeoutFuture::new(expiry).await;
+		content.track();
+
+		let style = (*span).style();
+
+		match expanded() {
+			true => {
+				let _ = style.remove_property("-webkit-box-orient");
+				let _ = style.remove_property("-webkit-line-clamp");
+			},
+
+			false => {
+				let _ = style.set_property("-webkit-box-orient", "vertical");
+				let _ = style.set_property("-webkit-line-clamp", &max_lines.to_string());
+
+				set_overflowing(span.scroll_height() > span.client_height());
+			},
+		}
+	});
+
+	let font_size = toast.font_size.clone().unwrap_or_else(|| "var(--leptoaster-font-size)".into());
+	let font_weight = toast.font_weight.clone().unwrap_or_else(|| "var(--leptoaster-font-weight)".into());
+
+	let message = match (toast.body, toast.html) {
+		(Some(body), _) => view! {
+			<div
+				style:color=text_color
+				style:font-size=font_size
+				style:line-height="var(--leptoaster-line-height)"
+				style:font-family="var(--leptoaster-font-family)"
+				style:flex-grow="1"
+				style:min-width="0"
+				on:click=move |event| event.stop_propagation()
+			>
+				{body.run()}
+			</div>
+		}.into_view(),
+
+		(None, Some(html)) => view! {
+			<span
+				node_ref=message_ref
+				inner_html=html
+				style:color=text_color
+				style:font-size=font_size
+				style:line-height="var(--leptoaster-line-height)"
+				style:font-family="var(--leptoaster-font-family)"
+				style:font-weight=font_weight
+				style:display=move || get_message_display(clamped())
+				style:flex-grow="1"
+				style:max-width="100%"
+				style:white-space=toast.multiline.then_some("pre-wrap")
+				style:text-overflow=(!toast.multiline).then_some("ellipsis")
+				style:overflow=move || (!toast.multiline || clamped()).then_some("hidden")
+			/>
+		}.into_view(),
+
+		(None, None) => view! {
+			<span
+				node_ref=message_ref
+				style:color=text_color
+				style:font-size=font_size
+				style:line-height="var(--leptoaster-line-height)"
+				style:font-family="var(--leptoaster-font-family)"
+				style:font-weight=font_weight
+				style:display=move || get_message_display(clamped())
+				style:flex-grow="1"
+				style:max-width="100%"
+				style:white-space=toast.multiline.then_some("pre-wrap")
+				style:text-overflow=(!toast.multiline).then_some("ellipsis")
+				style:overflow=move || (!toast.multiline || clamped()).then_some("hidden")
+			>
+				{move || match (toast.progress_message, updated()) {
+					(Some(progress_message), _) => progress_message.get(),
+					(None, true) => content.with(|content| content.message.clone()),
+					(None, false) => content.with(|content| content.message.chars().take(revealed()).collect::<String>()),
+				}}
+			</span>
+		}.into_view(),
+	};
+
+	let progress_expiry = move || {
+		toast.repeat_count.track();
+		paused.track();
+		content.with(|content| content.expiry.filter(|_| content.progress))
+	};
+
+	let group = store_value(toast.group.clone().unwrap_or_default());
+
+	let group_expanded = move || {
+		expanded_groups.is_some_and(|expanded_groups| {
+			group.with_value(|group| expanded_groups.with(|expanded_groups| expanded_groups.contains(group)))
+		})
+	};
+
+	let leads_group = move || expanded_groups.is_some() && group_count() > 1;
+
+	let toggle_group = move || {
+		if let Some(expanded_groups) = expanded_groups {
+			let group = group.get_value();
+
+			expanded_groups.update(|expanded_groups| match expanded_groups.contains(&group) {
+				true => expanded_groups.retain(|expanded_group| *expanded_group != group),
+				false => expanded_groups.push(group),
+			});
+		}
+	};
+
+	let role = toast.role.clone();
+	let hover_paused = store_value(false);
+
+	let on_click = store_value(toast.on_click.clone());
+	let has_on_click = toast.on_click.is_some();
+
+	let dismisses_on_click = move || content.with(|content| content.dismissable) && toast.dismiss_on_click;
+	let is_clickable = move || !toast.click_through && (has_on_click || dismisses_on_click());
 
-		if toast.clear_signal.get_untracked() {
+	let swipe_start = store_value(None::<i32>);
+	let suppress_click = store_value(false);
+	let touch_pointer = store_value(false);
+	let (swipe_offset, set_swipe_offset) = create_signal(0);
+
+	let handle_pointerdown = move |event: ev::PointerEvent| {
+		touch_pointer.set_value(is_tap_pointer(&event.pointer_type()));
+
+		if !swipe_to_dismiss || !content.with_untracked(|content| content.dismissable) {
 			return;
 		}
 
-		toast.clear_signal.set(true);
-	});
+		swipe_start.set_value(Some(event.client_x()));
 
-	create_resource(move || toast.clear_signal.get(), move |clear| async move {
-		if clear {
-			set_animation_name(slide_out_animation_name);
-			TimeoutFuture::new(animation_duration).await;
-			expect_toaster().remove(toast.id);
+		if let Some(element) = event.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok()) {
+			let _ = element.set_pointer_capture(event.pointer_id());
 		}
-	});
+	};
+
+	let handle_pointermove = move |event: ev::PointerEvent| {
+		if let Some(start) = swipe_start.get_value() {
+			set_swipe_offset(event.client_x() - start);
+		}
+	};
+
+	let handle_pointerup = move |_| {
+		if swipe_start.get_value().is_none() {
+			return;
+		}
+
+		swipe_start.set_value(None);
+
+		let offset = swipe_offset.get_untracked();
+		suppress_click.set_value(offset.abs() > SWIPE_CLICK_TOLERANCE);
+
+		match offset.abs() > SWIPE_THRESHOLD {
+			true => toast.clear_signal.set(true),
+			false => set_swipe_offset(0),
+		}
+	};
+
+	let handle_click = move || {
+		if toast.click_through || suppress_click.get_value() {
+			suppress_click.set_value(false);
+			return;
+		}
+
+		let tapped = touch_pointer.get_value();
+		touch_pointer.set_value(false);
 
-	let handle_click = move |_| {
-		if !toast.dismissable {
+		if !toggle_tapped_stack(stack_expanded, tapped) {
 			return;
 		}
 
-		toast.clear_signal.set(true);
+		on_click.with_value(|on_click| {
+			if let Some(on_click) = on_click {
+				on_click();
+			}
+		});
+
+		if untrack(dismisses_on_click) {
+			toast.clear_signal.set(true);
+		}
+	};
+
+	let handle_keydown = move |event: ev::KeyboardEvent| {
+		if event.target() != event.current_target() {
+			return;
+		}
+
+		if matches!(event.key().as_str(), "Enter" | " ") {
+			event.prevent_default();
+			handle_click();
+		}
 	};
 
 	view! {
 		<div
-			style:width="100%"
-			style:margin="12px 0"
-			style:padding="16px"
+			role=move || role.clone().or_else(|| (!toast.silent).then(|| get_live_semantics(&level()).0.into()))
+			class=toast.class
+			aria-live=move || (!toast.silent).then(|| get_live_semantics(&level()).1)
+			aria-atomic="true"
+			aria-hidden=toast.silent.then_some("true")
+			data-leptoaster-id=toast.id
+			data-leptoaster-level=move || get_level_name(&level())
+			tabindex=move || match keyboard_focus {
+				Some(keyboard_focus) => Some(get_tab_index(keyboard_focus.get() == Some(toast.id))),
+				None => is_clickable().then_some("0"),
+			}
+			style:width=toast.width.unwrap_or_else(|| "100%".into())
+			style:margin="var(--leptoaster-gap) 0"
+			style:padding="var(--leptoaster-padding)"
 			style:background-color=background_color
 			style:border="1px solid"
 			style:border-color=border_color
-			style:border-radius="4px"
+			style:border-radius="var(--leptoaster-border-radius)"
 			style:position="relative"
-			style:cursor=get_cursor(toast.dismissable)
+			style:cursor=move || get_cursor(is_clickable())
+			style:pointer-events=get_pointer_events(toast.click_through)
 			style:overflow="hidden"
 			style:box-sizing="border-box"
 			style:left=initial_left
 			style:right=initial_right
 			style:display="flex"
-			style:transition="transform 150ms ease-out, opacity 150ms ease-out"
-			style:transition-delay="250ms, 0s"
+			style:transition=move || get_transition(swipe_start.with_value(Option::is_some))
+			style:transition-delay="250ms, 0s, 0s"
+			style:translate=move || format!("{}px 0", swipe_offset())
+			style:touch-action=swipe_to_dismiss.then_some("pan-y")
 			style:animation-name=animation_name
 			style:animation-duration=format!("{}ms", animation_duration)
 			style:animation-timing-function="linear"
 			style:animation-fill-mode="forwards"
-			on:click=handle_click
+			on:click=move |_| handle_click()
+			on:keydown=handle_keydown
+			on:focus=move |_| {
+				if let Some(keyboard_focus) = keyboard_focus {
+					keyboard_focus.set(Some(toast.id));
+				}
+			}
+			on:mouseenter=move |_| {
+				if pause_on_hover && !toast.pause_signal.get_untracked() {
+					hover_paused.set_value(true);
+					toast.pause_signal.set(true);
+				}
+			}
+			on:mouseleave=move |_| {
+				if hover_paused.get_value() {
+					hover_paused.set_value(false);
+					toast.pause_signal.set(false);
+				}
+			}
+			on:pointerdown=handle_pointerdown
+			on:pointermove=handle_pointermove
+			on:pointerup=handle_pointerup
+			on:pointercancel=move |_| {
+				swipe_start.set_value(None);
+				set_swipe_offset(0);
+			}
 		>
-			<span
-				style:color=text_color
-				style:font-size="var(--leptoaster-font-size)"
-				style:line-height="var(--leptoaster-line-height)"
-				style:font-family="var(--leptoaster-font-family)"
-				style:font-weight="var(--leptoaster-font-weight)"
-				style:display="inline-block"
-				style:max-width="100%"
-				style:text-overflow="ellipsis"
-				style:overflow="hidden"
+			<Show
+				when=move || toast.pulse
 			>
-				{toast.message}
-			</span>
+				<div
+					class="leptoaster-pulse"
+					style:color=border_color
+					style:position="absolute"
+					style:inset="0"
+					style:border-radius="inherit"
+					style:pointer-events="none"
+				/>
+			</Show>
+
+			<Show
+				when=move || level() == ToastLevel::Loading
+			>
+				<span
+					aria-hidden="true"
+					class="leptoaster-spinner"
+					style:color=text_color
+					style:flex-shrink="0"
+					style:width="var(--leptoaster-line-height)"
+					style:height="var(--leptoaster-line-height)"
+					style:margin-right="8px"
+					style:border="2px solid currentColor"
+					style:border-right-color="transparent"
+					style:border-radius="50%"
+					style:box-sizing="border-box"
+				/>
+			</Show>
 
 			<Show
-				when=move || { toast.expiry.is_some() && toast.progress }
+				when=move || show_icons && level() != ToastLevel::Loading
 			>
+				<span
+					aria-hidden="true"
+					style:color=text_color
+					style:flex-shrink="0"
+					style:width="var(--leptoaster-line-height)"
+					style:height="var(--leptoaster-line-height)"
+					style:margin-right="8px"
+				>
+					<svg
+						viewBox="0 0 24 24"
+						width="100%"
+						height="100%"
+						fill="none"
+						stroke="currentColor"
+						stroke-width="2"
+						stroke-linecap="round"
+						stroke-linejoin="round"
+					>
+						<path d=icon_path />
+					</svg>
+				</span>
+			</Show>
+
+			{match title {
+				Some(title) => view! {
+					<div
+						style:display="flex"
+						style:flex-direction="column"
+						style:flex-grow="1"
+						style:min-width="0"
+					>
+						<span
+							style:color=text_color
+							style:font-size="var(--leptoaster-title-font-size)"
+							style:line-height="var(--leptoaster-line-height)"
+							style:font-family="var(--leptoaster-font-family)"
+							style:font-weight="var(--leptoaster-title-font-weight)"
+							style:max-width="100%"
+							style:text-overflow="ellipsis"
+							style:overflow="hidden"
+						>
+							{title}
+						</span>
+
+						{message}
+					</div>
+				}.into_view(),
+
+				None => message.into_view(),
+			}}
+
+			<Show
+				when=move || overflowing() || expanded()
+			>
+				<button
+					aria-expanded=move || expanded().to_string()
+					style:padding="0 0 0 12px"
+					style:border="none"
+					style:background="none"
+					style:color=text_color
+					style:font-size="var(--leptoaster-font-size)"
+					style:line-height="var(--leptoaster-line-height)"
+					style:font-family="var(--leptoaster-font-family)"
+					style:font-weight="var(--leptoaster-font-weight)"
+					style:text-decoration="underline"
+					style:white-space="nowrap"
+					style:align-self="flex-end"
+					style:cursor="pointer"
+					style:pointer-events="auto"
+					on:click=move |event| {
+						event.stop_propagation();
+						set_expanded.update(|expanded| *expanded = !*expanded);
+					}
+				>
+					{
+						let show_more_label = show_more_label.clone();
+						let show_less_label = show_less_label.clone();
+
+						move || match expanded() {
+							true => show_less_label.clone(),
+							false => show_more_label.clone(),
+						}
+					}
+				</button>
+			</Show>
+
+			<Show
+				when=move || { toast.repeat_count.get() > 1 }
+			>
+				<span
+					style:margin-left="8px"
+					style:padding="0 6px"
+					style:border="1px solid"
+					style:border-color=border_color
+					style:border-radius="8px"
+					style:color=text_color
+					style:font-size="var(--leptoaster-font-size)"
+					style:line-height="var(--leptoaster-line-height)"
+					style:font-family="var(--leptoaster-font-family)"
+					style:font-weight="var(--leptoaster-font-weight)"
+					style:white-space="nowrap"
+					style:align-self="flex-start"
+				>
+					{move || format!("\u{00d7}{}", toast.repeat_count.get())}
+				</span>
+			</Show>
+
+			<Show
+				when=leads_group
+			>
+				<button
+					aria-expanded=move || group_expanded().to_string()
+					style:padding="0 0 0 12px"
+					style:border="none"
+					style:background="none"
+					style:color=text_color
+					style:font-size="var(--leptoaster-font-size)"
+					style:line-height="var(--leptoaster-line-height)"
+					style:font-family="var(--leptoaster-font-family)"
+					style:font-weight="var(--leptoaster-font-weight)"
+					style:text-decoration="underline"
+					style:white-space="nowrap"
+					style:cursor="pointer"
+					style:pointer-events="auto"
+					on:click=move |event| {
+						event.stop_propagation();
+						toggle_group();
+					}
+				>
+					{move || group_count_label.with_value(|label| {
+						group.with_value(|group| get_group_count_text(label, group_count(), group))
+					})}
+				</button>
+			</Show>
+
+			{actions.into_iter().map(|(label, callback)| view! {
+				<button
+					style:padding="0 0 0 12px"
+					style:border="none"
+					style:background="none"
+					style:color=text_color
+					style:font-size="var(--leptoaster-font-size)"
+					style:line-height="var(--leptoaster-line-height)"
+					style:font-family="var(--leptoaster-font-family)"
+					style:font-weight="var(--leptoaster-font-weight)"
+					style:text-decoration="underline"
+					style:white-space="nowrap"
+					style:cursor="pointer"
+					style:pointer-events="auto"
+					on:click=move |event| {
+						event.stop_propagation();
+						callback();
+						toast.clear_signal.set(true);
+					}
+				>
+					{label}
+				</button>
+			}).collect_view()}
+
+			<Show
+				when=move || toast.acknowledge
+			>
+				<button
+					style:padding="0 0 0 12px"
+					style:border="none"
+					style:background="none"
+					style:color=text_color
+					style:font-size="var(--leptoaster-font-size)"
+					style:line-height="var(--leptoaster-line-height)"
+					style:font-family="var(--leptoaster-font-family)"
+					style:font-weight="var(--leptoaster-font-weight)"
+					style:text-decoration="underline"
+					style:white-space="nowrap"
+					style:cursor="pointer"
+					style:pointer-events="auto"
+					on:click=move |event| {
+						event.stop_propagation();
+						toast.clear_signal.set(true);
+					}
+				>
+					{dismiss_label.clone()}
+				</button>
+			</Show>
+
+			<Show
+				when=move || toast.minimizable
+			>
+				<button
+					aria-label=minimize_label.clone()
+					style:padding="0 0 0 12px"
+					style:border="none"
+					style:background="none"
+					style:color=text_color
+					style:font-size="var(--leptoaster-font-size)"
+					style:line-height="var(--leptoaster-line-height)"
+					style:font-family="var(--leptoaster-font-family)"
+					style:font-weight="var(--leptoaster-font-weight)"
+					style:cursor="pointer"
+					style:pointer-events="auto"
+					on:click=move |event| {
+						event.stop_propagation();
+						toast.minimized.set(true);
+					}
+				>
+					"\u{2212}"
+				</button>
+			</Show>
+
+			<Show
+				when=move || show_close_button
+			>
+				<button
+					aria-label=close_label.clone()
+					style:padding="0 0 0 12px"
+					style:border="none"
+					style:background="none"
+					style:color=text_color
+					style:font-size="var(--leptoaster-font-size)"
+					style:line-height="var(--leptoaster-line-height)"
+					style:font-family="var(--leptoaster-font-family)"
+					style:font-weight="var(--leptoaster-font-weight)"
+					style:align-self="flex-start"
+					style:cursor="pointer"
+					style:pointer-events="auto"
+					on:click=move |event| {
+						event.stop_propagation();
+						toast.clear_signal.set(true);
+					}
+				>
+					"\u{00d7}"
+				</button>
+			</Show>
+
+			{move || progress_expiry().map(|expiry| view! {
 				<div
 					style:height="var(--leptoaster-progress-height)"
 					style:width="100%"
-					style:background-color=text_color
 					style:position="absolute"
-					style:bottom="0"
+					style:top=get_progress_top(toast.progress_position)
+					style:bottom=get_progress_bottom(toast.progress_position)
 					style:left="0"
-					style:animation-name="leptoaster-progress"
-					style:animation-duration=format!("{}ms", toast.expiry.unwrap())
-					style:animation-timing-function="linear"
-					style:animation-fill-mode="forwards"
-				/>
-			</Show>
+				>
+					<div
+						style:height="100%"
+						style:width="100%"
+						style:background-color=text_color
+						style:animation-name=get_progress_animation_name(toast.progress_direction)
+						style:animation-duration=format!("{}ms", expiry)
+						style:animation-delay=format!("{}ms", get_progress_delay(updated.get_untracked(), reveal_duration, elapsed.get_value()))
+						style:animation-timing-function="linear"
+						style:animation-fill-mode="forwards"
+						style:animation-play-state=move || get_play_state(paused.get())
+					/>
+
+					{progress_content.clone().map(|content| view! {
+						<div
+							style:position="absolute"
+							style:inset="0"
+							style:display="flex"
+							style:align-items="center"
+						>
+							{content.run()}
+						</div>
+					})}
+				</div>
+			})}
 		</div>
 	}
 }
 
-fn get_slide_in_animation_name(position: &ToastPosition) -> &'static str {
-	match position {
-		ToastPosition::TopLeft | ToastPosition::BottomLeft => "leptoaster-slide-in-left",
-		ToastPosition::TopRight | ToastPosition::BottomRight => "leptoaster-slide-in-right",
+/// Runs the expiry timer of the supplied toast, clearing the toast once it expires.
+/// The timer restarts whenever the toast is updated with `ToasterContext::update` or
+/// repeated through its deduplication key, and is paused while the toast's
+/// `pause_signal` is set or the toaster is paused with `ToasterContext::pause_all`.
+///
+/// The built-in `Toast` component runs the same timer, so custom toast components
+/// rendering the toaster's `queue` can reuse its expiry behavior without mounting the
+/// `Toaster`. The timer should be started by the component rendering the toast.
+///
+/// # Examples
+/// ```
+/// #[component]
+/// fn MyToast(toast: ToastData) -> impl IntoView {
+///     use_toast_timer(&toast);
+///
+///     view! {
+///         <div>{toast.message.clone()}</div>
+///     }
+/// }
+/// ```
+pub fn use_toast_timer(toast: &ToastData) {
+	start_toast_timer(toast, 0);
+}
+
+/// Runs the expiry timer of the supplied toast, delaying its first run by the supplied
+/// number of milliseconds. Returns the unpaused time in milliseconds that has elapsed
+/// since the timer last started.
+///
+/// The timer outlives the component that started it, so it stops waiting as soon as
+/// that component is cleaned up rather than touching its disposed values.
+fn start_toast_timer(toast: &ToastData, delay: u32) -> StoredValue<u32> {
+	let content = create_content_memo(toast);
+	let repeat_count = toast.repeat_count;
+	let pause_signal = toast.pause_signal;
+	let toaster_paused = expect_toaster().is_paused();
+	let clear_signal = toast.clear_signal;
+	let cancelled = create_cleanup_flag();
+	let generation = store_value(0_u32);
+	let elapsed = store_value(0_u32);
+
+	create_resource(move || (content.get(), repeat_count.get()), move |(content, _)| {
+		let cancelled = Rc::clone(&cancelled);
+
+		async move {
+			let Some(current_generation) = generation.try_update_value(|generation| {
+				*generation += 1;
+				*generation
+			}) else {
+				return;
+			};
+
+			elapsed.try_set_value(0);
+
+			if current_generation == 1 && delay > 0 {
+				TimeoutFuture::new(delay).await;
+			}
+
+			let Some(expiry) = content.expiry else {
+				return;
+			};
+
+			let is_current = || is_current_run(&cancelled, generation, current_generation);
+
+			let completed = wait_unpaused(
+				expiry,
+				TimeoutFuture::new,
+				|| is_current().then(|| pause_signal.get_untracked() || toaster_paused.get_untracked()),
+				|remaining| is_current() && elapsed.try_set_value(expiry - remaining).is_none(),
+			).await;
+
+			if !completed || !is_current() || clear_signal.try_get_untracked() != Some(false) {
+				return;
+			}
+
+			clear_signal.set(true);
+		}
+	});
+
+	elapsed
+}
+
+/// Returns a flag that is raised once the current reactive owner is cleaned up.
+fn create_cleanup_flag() -> Rc<Cell<bool>> {
+	let flag = Rc::new(Cell::new(false));
+
+	on_cleanup({
+		let flag = Rc::clone(&flag);
+		move || flag.set(true)
+	});
+
+	flag
+}
+
+/// Returns `true` if the timer run of the supplied generation is still the latest one
+/// and the component that started it has not been cleaned up.
+fn is_current_run(cancelled: &Cell<bool>, generation: StoredValue<u32>, current_generation: u32) -> bool {
+	!cancelled.get() && generation.try_get_value() == Some(current_generation)
+}
+
+/// Creates a memo of the content of the supplied toast that follows its entry in the
+/// toast queue, keeping the last known content once the toast leaves the queue.
+fn create_content_memo(toast: &ToastData) -> Memo<ToastContent> {
+	let queue = expect_toaster().queue;
+	let toast_id = toast.id;
+	let initial_content = ToastContent::from(toast);
+
+	create_memo(move |previous: Option<&ToastContent>| {
+		queue.with(|queue| queue.iter().find(|queued| queued.id == toast_id).map(ToastContent::from))
+			.or_else(|| previous.cloned())
+			.unwrap_or_else(|| initial_content.clone())
+	})
+}
+
+/// The parts of a toast that can be changed with `ToasterContext::update`.
+#[derive(Clone, PartialEq)]
+struct ToastContent {
+	message: String,
+	level: ToastLevel,
+	dismissable: bool,
+	expiry: Option<u32>,
+	progress: bool,
+}
+
+impl From<&ToastData> for ToastContent {
+	fn from(toast: &ToastData) -> Self {
+		ToastContent {
+			message: toast.message.clone(),
+			level: toast.level.clone(),
+			dismissable: toast.dismissable,
+			expiry: toast.expiry,
+			progress: toast.progress,
+		}
 	}
 }
 
-fn get_slide_out_animation_name(position: &ToastPosition) -> &'static str {
+/// A minimized toast, rendered as a compact chip that expands the toast on click.
+#[component]
+pub fn ToastChip(toast: ToastData) -> impl IntoView {
+	let (background_color, border_color, text_color) = get_colors(&toast.level);
+	let expand_label = use_toast_labels().expand;
+
+	create_resource(move || toast.clear_signal.get(), move |clear| async move {
+		if clear {
+			expect_toaster().remove(toast.id);
+		}
+	});
+
+	view! {
+		<button
+			aria-label=expand_label
+			title=toast.message.clone()
+			style:max-width="120px"
+			style:margin="12px 12px 0 0"
+			style:padding="4px 12px"
+			style:background-color=background_color
+			style:border="1px solid"
+			style:border-color=border_color
+			style:border-radius="12px"
+			style:color=text_color
+			style:font-size="var(--leptoaster-font-size)"
+			style:line-height="var(--leptoaster-line-height)"
+			style:font-family="var(--leptoaster-font-family)"
+			style:font-weight="var(--leptoaster-font-weight)"
+			style:white-space="nowrap"
+			style:text-overflow="ellipsis"
+			style:overflow="hidden"
+			style:cursor="pointer"
+			on:click=move |_| toast.minimized.set(false)
+		>
+			{toast.message}
+		</button>
+	}
+}
+
+/// Returns the position on screen of the supplied position, mirroring the left
+/// and right positions in right-to-left layouts.
+pub(crate) fn get_physical_position(position: &ToastPosition, rtl: bool) -> ToastPosition {
+	match (position, rtl) {
+		(ToastPosition::TopLeft, true) => ToastPosition::TopRight,
+		(ToastPosition::TopRight, true) => ToastPosition::TopLeft,
+		(ToastPosition::BottomLeft, true) => ToastPosition::BottomRight,
+		(ToastPosition::BottomRight, true) => ToastPosition::BottomLeft,
+		_ => position.clone(),
+	}
+}
+
+fn get_slide_origin(position: &ToastPosition) -> SlideOrigin {
 	match position {
-		ToastPosition::TopLeft | ToastPosition::BottomLeft => "leptoaster-slide-out-left",
-		ToastPosition::TopRight | ToastPosition::BottomRight => "leptoaster-slide-out-right",
+		ToastPosition::TopLeft | ToastPosition::BottomLeft => SlideOrigin::Left,
+		ToastPosition::TopRight | ToastPosition::BottomRight => SlideOrigin::Right,
+		ToastPosition::TopCenter => SlideOrigin::Top,
+		ToastPosition::BottomCenter => SlideOrigin::Bottom,
+	}
+}
+
+fn get_enter_animation_name(animation: ToastAnimation, slide_origin: SlideOrigin) -> &'static str {
+	match (animation, slide_origin) {
+		(ToastAnimation::Slide, SlideOrigin::Left) => "leptoaster-slide-in-left",
+		(ToastAnimation::Slide, SlideOrigin::Right) => "leptoaster-slide-in-right",
+		(ToastAnimation::Slide, SlideOrigin::Top) => "leptoaster-slide-in-top",
+		(ToastAnimation::Slide, SlideOrigin::Bottom) => "leptoaster-slide-in-bottom",
+		(ToastAnimation::PopSlide, _) => "leptoaster-pop-in",
+		(ToastAnimation::Pop, _) => "leptoaster-bounce-in",
+		(ToastAnimation::Fade, _) => "leptoaster-fade-in",
 	}
 }
 
-fn get_colors(level: &ToastLevel) -> (&'static str, &'static str, &'static str) {
+fn get_exit_animation_name(animation: ToastAnimation, slide_origin: SlideOrigin) -> &'static str {
+	match (animation, slide_origin) {
+		(ToastAnimation::Fade, _) => "leptoaster-fade-out",
+		(ToastAnimation::Pop, _) => "leptoaster-pop-out",
+		(_, SlideOrigin::Left) => "leptoaster-slide-out-left",
+		(_, SlideOrigin::Right) => "leptoaster-slide-out-right",
+		(_, SlideOrigin::Top) => "leptoaster-slide-out-top",
+		(_, SlideOrigin::Bottom) => "leptoaster-slide-out-bottom",
+	}
+}
+
+fn get_live_semantics(level: &ToastLevel) -> (&'static str, &'static str) {
+	match level {
+		ToastLevel::Warn | ToastLevel::Error => ("alert", "assertive"),
+		ToastLevel::Info | ToastLevel::Success | ToastLevel::Loading | ToastLevel::Custom { .. } => ("status", "polite"),
+	}
+}
+
+fn get_group_count_text(label: &str, count: usize, group: &str) -> String {
+	label
+		.replace("{count}", &count.to_string())
+		.replace("{group}", group)
+}
+
+fn get_level_name(level: &ToastLevel) -> &'static str {
+	match level {
+		ToastLevel::Info => "info",
+		ToastLevel::Success => "success",
+		ToastLevel::Warn => "warn",
+		ToastLevel::Error => "error",
+		ToastLevel::Loading => "loading",
+		ToastLevel::Custom { .. } => "custom",
+	}
+}
+
+fn get_icon_path(level: &ToastLevel) -> &'static str {
+	match level {
+		ToastLevel::Info | ToastLevel::Loading | ToastLevel::Custom { .. } => "M12 2a10 10 0 1 0 0 20a10 10 0 1 0 0-20zM12 16v-4M12 8h.01",
+		ToastLevel::Success => "M20 6L9 17l-5-5",
+		ToastLevel::Warn => "M10.29 3.86L1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0zM12 9v4M12 17h.01",
+		ToastLevel::Error => "M12 2a10 10 0 1 0 0 20a10 10 0 1 0 0-20zM12 8v4M12 16h.01",
+	}
+}
+
+fn get_colors(level: &ToastLevel) -> (String, String, String) {
 	match level {
 		ToastLevel::Info => (
-			"var(--leptoaster-info-background-color)",
-			"var(--leptoaster-info-border-color)",
-			"var(--leptoaster-info-text-color)",
+			"var(--leptoaster-info-background-color)".into(),
+			"var(--leptoaster-info-border-color)".into(),
+			"var(--leptoaster-info-text-color)".into(),
 		),
 
 		ToastLevel::Success => (
-			"var(--leptoaster-success-background-color)",
-			"var(--leptoaster-success-border-color)",
-			"var(--leptoaster-success-text-color)",
+			"var(--leptoaster-success-background-color)".into(),
+			"var(--leptoaster-success-border-color)".into(),
+			"var(--leptoaster-success-text-color)".into(),
 		),
 
 		ToastLevel::Warn => (
-			"var(--leptoaster-warn-background-color)",
-			"var(--leptoaster-warn-border-color)",
-			"var(--leptoaster-warn-text-color)",
+			"var(--leptoaster-warn-background-color)".into(),
+			"var(--leptoaster-warn-border-color)".into(),
+			"var(--leptoaster-warn-text-color)".into(),
 		),
 
 		ToastLevel::Error => (
-			"var(--leptoaster-error-background-color)",
-			"var(--leptoaster-error-border-color)",
-			"var(--leptoaster-error-text-color)",
+			"var(--leptoaster-error-background-color)".into(),
+			"var(--leptoaster-error-border-color)".into(),
+			"var(--leptoaster-error-text-color)".into(),
+		),
+
+		ToastLevel::Loading => (
+			"var(--leptoaster-loading-background-color)".into(),
+			"var(--leptoaster-loading-border-color)".into(),
+			"var(--leptoaster-loading-text-color)".into(),
+		),
+
+		ToastLevel::Custom { background, border, text } => (
+			background.clone(),
+			border.clone(),
+			text.clone(),
 		),
 	}
 }
 
-fn get_initial_positions(position: &ToastPosition) -> (&'static str, &'static str) {
+fn get_initial_positions(animation: ToastAnimation, slide_origin: SlideOrigin, position: &ToastPosition) -> (&'static str, &'static str) {
+	match (animation, slide_origin) {
+		(ToastAnimation::Slide, SlideOrigin::Left) => ("calc((var(--leptoaster-width) + 12px * 2) * -1)", "auto"),
+		(ToastAnimation::Slide, SlideOrigin::Right) => ("auto", "calc((var(--leptoaster-width) + 12px * 2) * -1)"),
+		(ToastAnimation::Slide, SlideOrigin::Top | SlideOrigin::Bottom) => get_resting_positions(position),
+		(ToastAnimation::PopSlide | ToastAnimation::Pop | ToastAnimation::Fade, _) => get_resting_positions(position),
+	}
+}
+
+fn get_resting_positions(position: &ToastPosition) -> (&'static str, &'static str) {
 	match position {
-		ToastPosition::TopLeft | ToastPosition::BottomLeft => ("calc((var(--leptoaster-width) + 12px * 2) * -1)", "auto"),
-		ToastPosition::TopRight | ToastPosition::BottomRight => ("auto", "calc((var(--leptoaster-width) + 12px * 2) * -1)"),
+		ToastPosition::TopLeft | ToastPosition::BottomLeft => ("0", "auto"),
+		ToastPosition::TopRight | ToastPosition::BottomRight => ("auto", "0"),
+		ToastPosition::TopCenter | ToastPosition::BottomCenter => ("0", "auto"),
+	}
+}
+
+fn get_progress_animation_name(progress_direction: ProgressDirection) -> &'static str {
+	match progress_direction {
+		ProgressDirection::Shrink => "leptoaster-progress",
+		ProgressDirection::Grow => "leptoaster-progress-grow",
+	}
+}
+
+fn get_progress_top(progress_position: ProgressPosition) -> Option<&'static str> {
+	match progress_position {
+		ProgressPosition::Top => Some("0"),
+		ProgressPosition::Bottom => None,
 	}
 }
 
-fn get_cursor(dismissable: bool) -> &'static str {
-	match dismissable {
+fn get_progress_bottom(progress_position: ProgressPosition) -> Option<&'static str> {
+	match progress_position {
+		ProgressPosition::Top => None,
+		ProgressPosition::Bottom => Some("0"),
+	}
+}
+
+fn get_progress_delay(updated: bool, reveal_duration: u32, elapsed: u32) -> i64 {
+	match (updated, elapsed) {
+		(_, 1..) => -i64::from(elapsed),
+		(true, 0) => 0,
+		(false, 0) => i64::from(reveal_duration),
+	}
+}
+
+fn get_tab_index(focused: bool) -> &'static str {
+	match focused {
+		true => "0",
+		false => "-1",
+	}
+}
+
+fn get_transition(swiping: bool) -> &'static str {
+	match swiping {
+		true => "transform 150ms ease-out, opacity 150ms ease-out, translate 0s",
+		false => "transform 150ms ease-out, opacity 150ms ease-out, translate 150ms ease-out",
+	}
+}
+
+fn get_message_display(clamped: bool) -> &'static str {
+	match clamped {
+		true => "-webkit-box",
+		false => "inline-block",
+	}
+}
+
+fn get_cursor(clickable: bool) -> &'static str {
+	match clickable {
 		true => "pointer",
 		false => "default",
 	}
 }
 
+fn get_pointer_events(click_through: bool) -> &'static str {
+	match click_through {
+		true => "none",
+		false => "auto",
+	}
+}
+
+fn get_play_state(paused: bool) -> &'static str {
+	match paused {
+		true => "paused",
+		false => "running",
+	}
+}
+
+/// Returns `true` for the pointer types that tap rather than hover, which are touch and
+/// pen pointers.
+fn is_tap_pointer(pointer_type: &str) -> bool {
+	matches!(pointer_type, "touch" | "pen")
+}
+
+/// Toggles whether the supplied stack is expanded when the toast was tapped. Returns
+/// `false` if the tap only expanded the stack and the click should go no further.
+fn toggle_tapped_stack(stack_expanded: Option<RwSignal<bool>>, tapped: bool) -> bool {
+	let Some(stack_expanded) = stack_expanded.filter(|_| tapped) else {
+		return true;
+	};
+
+	let expanded = stack_expanded.get_untracked();
+	stack_expanded.set(!expanded);
+
+	expanded
+}
+
+/// Waits for the supplied number of milliseconds, sleeping with the supplied function
+/// and not counting any time spent while the paused function returns `Some(true)`.
+/// Stops early and returns `false` once the paused function returns `None` or the tick
+/// function returns `false`.
+async fn wait_unpaused<F: Future<Output = ()>>(
+	duration: u32,
+	sleep: impl Fn(u32) -> F,
+	paused: impl Fn() -> Option<bool>,
+	on_tick: impl Fn(u32) -> bool,
+) -> bool {
+	let mut remaining = duration;
+
+	while remaining > 0 {
+		let tick = remaining.min(TIMER_TICK);
+		sleep(tick).await;
+
+		let Some(is_paused) = paused() else {
+			return false;
+		};
+
+		remaining = advance_timer(remaining, tick, is_paused);
+
+		if !is_paused && !on_tick(remaining) {
+			return false;
+		}
+	}
+
+	true
+}
+
+/// Returns the milliseconds left on a timer after a tick of the supplied number of
+/// milliseconds. Ticks spent paused do not count.
+fn advance_timer(remaining: u32, tick: u32, paused: bool) -> u32 {
+	match paused {
+		true => remaining,
+		false => remaining.saturating_sub(tick),
+	}
+}
+
+fn now() -> f64 {
+	window()
+		.performance()
+		.map_or(0.0, |performance| performance.now())
+}
+
+fn prefers_reduced_motion() -> bool {
+	window()
+		.match_media("(prefers-reduced-motion: reduce)")
+		.ok()
+		.flatten()
+		.is_some_and(|query| query.matches())
+}
+
 pub use crate::toast::builder::ToastBuilder;
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		future::{self, Future},
+		task::{Context, Poll, Waker},
+		pin::pin,
+		cell::{Cell, RefCell},
+	};
+	use leptos::*;
+	use crate::toaster::context::ToasterContext;
+	use super::{
+		advance_timer,
+		wait_unpaused,
+		create_cleanup_flag,
+		is_current_run,
+		is_tap_pointer,
+		toggle_tapped_stack,
+		TIMER_TICK,
+	};
+
+	fn with_runtime(test: impl FnOnce()) {
+		let runtime = create_runtime();
+		test();
+		runtime.dispose();
+	}
+
+	fn block_on<F: Future>(future: F) -> F::Output {
+		let mut future = pin!(future);
+		let mut context = Context::from_waker(Waker::noop());
+
+		loop {
+			if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+				return output;
+			}
+		}
+	}
+
+	#[test]
+	fn pausing_all_extends_the_expiry() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			let is_paused = toaster.is_paused();
+			let time = Cell::new(0);
+			let ticks = Cell::new(0);
+
+			let completed = block_on(wait_unpaused(
+				1_000,
+				|tick| {
+					time.set(time.get() + tick);
+
+					match time.get() {
+						200 => toaster.pause_all(),
+						700 => toaster.resume_all(),
+						_ => (),
+					}
+
+					future::ready(())
+				},
+				|| Some(is_paused.get_untracked()),
+				|_| {
+					ticks.set(ticks.get() + 1);
+					true
+				},
+			));
+
+			assert!(completed);
+			assert_eq!(time.get(), 1_500);
+			assert_eq!(ticks.get(), 1_000 / TIMER_TICK);
+			assert!(!is_paused.get_untracked());
+		});
+	}
+
+	#[test]
+	fn paused_ticks_do_not_count() {
+		assert_eq!(advance_timer(1_000, TIMER_TICK, false), 1_000 - TIMER_TICK);
+		assert_eq!(advance_timer(1_000, TIMER_TICK, true), 1_000);
+		assert_eq!(advance_timer(20, TIMER_TICK, false), 0);
+	}
+
+	#[test]
+	fn disposing_the_owner_stops_the_wait() {
+		with_runtime(|| {
+			let (cancelled, generation, disposer) = {
+				let create_scope = as_child_of_current_owner(|()| (create_cleanup_flag(), store_value(1_u32)));
+				let ((cancelled, generation), disposer) = create_scope(());
+				(cancelled, generation, disposer)
+			};
+
+			let disposer = RefCell::new(Some(disposer));
+			let time = Cell::new(0);
+			let ticks = Cell::new(0);
+
+			let completed = block_on(wait_unpaused(
+				1_000,
+				|tick| {
+					time.set(time.get() + tick);
+
+					if time.get() == 200 {
+						disposer.borrow_mut().take();
+					}
+
+					future::ready(())
+				},
+				|| is_current_run(&cancelled, generation, 1).then_some(false),
+				|_| {
+					ticks.set(ticks.get() + 1);
+					true
+				},
+			));
+
+			assert!(!completed);
+			assert!(cancelled.get());
+			assert_eq!(generation.try_get_value(), None);
+			assert_eq!(time.get(), 200);
+			assert_eq!(ticks.get(), 3);
+		});
+	}
+
+	#[test]
+	fn disposing_the_owner_stops_the_ticks() {
+		with_runtime(|| {
+			let (cancelled, elapsed, disposer) = {
+				let create_scope = as_child_of_current_owner(|()| (create_cleanup_flag(), store_value(0_u32)));
+				let ((cancelled, elapsed), disposer) = create_scope(());
+				(cancelled, elapsed, disposer)
+			};
+
+			let disposer = RefCell::new(Some(disposer));
+			let ticks = Cell::new(0);
+
+			let completed = block_on(wait_unpaused(
+				1_000,
+				|_| future::ready(()),
+				|| Some(false),
+				|remaining| {
+					ticks.set(ticks.get() + 1);
+
+					if remaining == 800 {
+						disposer.borrow_mut().take();
+					}
+
+					!cancelled.get() && elapsed.try_set_value(1_000 - remaining).is_none()
+				},
+			));
+
+			assert!(!completed);
+			assert_eq!(ticks.get(), 4);
+			assert_eq!(elapsed.try_get_value(), None);
+		});
+	}
+
+	#[test]
+	fn only_taps_toggle_a_stack() {
+		with_runtime(|| {
+			let stack_expanded = create_rw_signal(false);
+
+			assert!(toggle_tapped_stack(Some(stack_expanded), is_tap_pointer("mouse")));
+			assert!(!stack_expanded.get_untracked());
+
+			assert!(!toggle_tapped_stack(Some(stack_expanded), is_tap_pointer("touch")));
+			assert!(stack_expanded.get_untracked());
+
+			assert!(toggle_tapped_stack(Some(stack_expanded), is_tap_pointer("pen")));
+			assert!(!stack_expanded.get_untracked());
+
+			assert!(toggle_tapped_stack(None, is_tap_pointer("touch")));
+		});
+	}
+}
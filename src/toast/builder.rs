@@ -5,37 +5,115 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::{
+	rc::Rc,
+	time::Duration,
+};
 
 use leptos::*;
 
+use crate::toaster::ToasterConfig;
 use crate::toast::data::{
 	ToastId,
 	ToastLevel,
 	ToastPosition,
+	ToastAnimation,
+	SlideOrigin,
+	ProgressDirection,
+	ProgressPosition,
 	ToastData,
 };
 
 pub struct ToastBuilder {
+	title: Option<String>,
 	message: String,
+	progress_message: Option<Signal<String>>,
+	body: Option<ViewFn>,
+	html: Option<String>,
 
 	level: ToastLevel,
 
-	dismissable: bool,
-	expiry: Option<u32>,
-	progress: bool,
+	dismissable: Option<bool>,
+	dismiss_on_click: bool,
+	acknowledge: bool,
+	click_through: bool,
+	expiry: Option<Option<u32>>,
+	progress: Option<bool>,
+	progress_content: Option<ViewFn>,
+	progress_direction: ProgressDirection,
+	progress_position: ProgressPosition,
+	typewriter: Option<u32>,
+	max_lines: Option<u32>,
+	multiline: bool,
+	minimizable: bool,
+	pulse: bool,
+	silent: bool,
+	sticky: bool,
+
+	position: Option<ToastPosition>,
+	animation: ToastAnimation,
+	slide_from: Option<SlideOrigin>,
+	exit_animation: bool,
+
+	key: Option<String>,
+	dedup_key: Option<String>,
+	group: Option<String>,
+	tag: Option<String>,
+	role: Option<String>,
+	class: Option<String>,
+	width: Option<String>,
+	font_size: Option<String>,
+	font_weight: Option<String>,
 
-	position: ToastPosition,
+	actions: Vec<(String, Rc<dyn Fn()>)>,
+	on_click: Option<Rc<dyn Fn()>>,
+	on_show: Option<Rc<dyn Fn(ToastId)>>,
+	on_removed: Option<Rc<dyn Fn(ToastId, Duration)>>,
+	on_dismiss: Option<Rc<dyn Fn(ToastId)>>,
 }
 
 /// Builds a toast, allowing for the custimization of toast message,
 /// level, dismissability, expiry, and position.
 ///
 /// The defaults are:
+/// * `title`: `None`
+/// * `body`: `None`
+/// * `html`: `None`
 /// * `level`: `ToastLevel::Info`
-/// * `dismissable`: `true`
-/// * `expiry`: `2_500`
-/// * `progress`: `true`
+/// * `dismissable`: `true`, or `false` for loading toasts
+/// * `dismiss_on_click`: `true`
+/// * `acknowledge`: `false`
+/// * `click_through`: `false`
+/// * `expiry`: `2_500`, or `None` for loading toasts
+/// * `progress`: `true`, or `false` if the toast has no expiry
+/// * `progress_content`: `None`
+/// * `progress_direction`: `ProgressDirection::Shrink`
+/// * `progress_position`: `ProgressPosition::Bottom`
+/// * `typewriter`: `None`
+/// * `max_lines`: `None`
+/// * `multiline`: `false`
+/// * `minimizable`: `false`
+/// * `pulse`: `false`
+/// * `silent`: `false`
+/// * `sticky`: `false`
 /// * `position`: `ToastPosition::BottomLeft`
+/// * `animation`: `ToastAnimation::Slide`
+/// * `slide_from`: `None`
+/// * `exit_animation`: `true`
+/// * `key`: `None`
+/// * `dedup_key`: `None`
+/// * `group`: `None`
+/// * `tag`: `None`
+/// * `role`: `None`
+/// * `class`: `None`
+/// * `width`: `None`
+/// * `font_size`: `None`
+/// * `font_weight`: `None`
+/// * `actions`: `[]`
+///
+/// The defaults of `dismissable`, `expiry`, `progress`, and `position` can be changed
+/// for all toasts of a toaster with a `ToasterConfig`, which can also set a different
+/// default `expiry` for each level.
 ///
 /// # Examples
 /// ```
@@ -55,18 +133,161 @@ impl ToastBuilder {
 	#[must_use]
 	pub fn new(message: &str) -> Self {
 		ToastBuilder {
+			title: None,
 			message: message.into(),
+			progress_message: None,
+			body: None,
+			html: None,
 
 			level: ToastLevel::Info,
 
-			dismissable: true,
-			expiry: Some(2_500),
-			progress: true,
+			dismissable: None,
+			dismiss_on_click: true,
+			acknowledge: false,
+			click_through: false,
+			expiry: None,
+			progress: None,
+			progress_content: None,
+			progress_direction: ProgressDirection::Shrink,
+			progress_position: ProgressPosition::Bottom,
+			typewriter: None,
+			max_lines: None,
+			multiline: false,
+			minimizable: false,
+			pulse: false,
+			silent: false,
+			sticky: false,
+
+			position: None,
+			animation: ToastAnimation::Slide,
+			slide_from: None,
+			exit_animation: true,
+
+			key: None,
+			dedup_key: None,
+			group: None,
+			tag: None,
+			role: None,
+			class: None,
+			width: None,
+			font_size: None,
+			font_weight: None,
 
-			position: ToastPosition::BottomLeft,
+			actions: Vec::new(),
+			on_click: None,
+			on_show: None,
+			on_removed: None,
+			on_dismiss: None,
 		}
 	}
 
+	/// Constructs a new toast builder for a sticky error with the supplied message.
+	/// Sticky errors never expire and cannot be dismissed on click, so they stay on
+	/// screen until the user acknowledges them with the dismiss button.
+	///
+	/// # Examples
+	/// ```
+	/// let toast = ToastBuilder::error_sticky("The connection was lost.");
+	/// ```
+	#[must_use]
+	pub fn error_sticky(message: &str) -> Self {
+		ToastBuilder::new(message)
+			.with_level(ToastLevel::Error)
+			.with_expiry(None)
+			.with_dismissable(false)
+			.with_acknowledge(true)
+	}
+
+	/// Constructs a new toast builder for a loading toast with the supplied message.
+	/// Unless set otherwise, loading toasts never expire and cannot be dismissed on
+	/// click, so they stay on screen until they are updated or cleared.
+	///
+	/// # Examples
+	/// ```
+	/// let toast = ToastBuilder::loading("Uploading…");
+	/// ```
+	#[must_use]
+	pub fn loading(message: &str) -> Self {
+		ToastBuilder::new(message)
+			.with_level(ToastLevel::Loading)
+	}
+
+	/// Sets the title of the toast, which is rendered above the message.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("The file exceeds the maximum upload size.")
+	///     .with_title("Upload failed"); // sets the title of the toast.
+	/// ```
+	#[must_use]
+	pub fn with_title(mut self, title: &str) -> Self {
+		self.title = Some(title.into());
+		self
+	}
+
+	/// Replaces the message of the toast with one derived from the supplied progress
+	/// signal, updating it whenever the progress changes. The typewriter effect does
+	/// not apply to progress messages.
+	///
+	/// # Examples
+	/// ```
+	/// let progress = create_rw_signal(0.0);
+	///
+	/// ToastBuilder::new("Downloading…")
+	///     .with_expiry(None)
+	///     .with_progress_message(progress, |progress| {
+	///         format!("Downloading… {:.0}%", progress * 100.0)
+	///     });
+	/// ```
+	#[must_use]
+	pub fn with_progress_message(
+		mut self,
+		progress: RwSignal<f32>,
+		format: impl Fn(f32) -> String + 'static,
+	) -> Self {
+		self.progress_message = Some(Signal::derive(move || format(progress.get())));
+		self
+	}
+
+	/// Renders the supplied view in place of the message of the toast, allowing for
+	/// links, buttons or small forms inside the toast. The message is still used as
+	/// the text of the toast when it is minimized.
+	///
+	/// Clicks inside the view never dismiss the toast, so interactive elements can be
+	/// used freely. Clicks on the rest of the toast still dismiss it if the toast is
+	/// dismissable.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("A new version is available.")
+	///     .with_body_view(view! {
+	///         <a href="/changelog">"See what's new"</a>
+	///     });
+	/// ```
+	#[must_use]
+	pub fn with_body_view(mut self, body: impl IntoView + Clone + 'static) -> Self {
+		self.body = Some(ViewFn::from(move || body.clone()));
+		self
+	}
+
+	/// Renders the supplied HTML string in place of the message of the toast. The
+	/// message is still used as the text of the toast when it is minimized.
+	///
+	/// The HTML is inserted as is, without any escaping or sanitization, so it must
+	/// never contain untrusted input. Use the message for plain text instead, which is
+	/// always escaped.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("Your changes were saved.")
+	///     .with_html("Your changes were <b>saved</b>.");
+	/// ```
+	#[must_use]
+	pub fn with_html(mut self, html: &str) -> Self {
+		self.html = Some(html.into());
+		self
+	}
+
 	/// Sets the level of the toast.
 	///
 	/// # Examples
@@ -90,11 +311,55 @@ impl ToastBuilder {
 	/// ```
 	#[must_use]
 	pub fn with_dismissable(mut self, dismissable: bool) -> Self {
-		self.dismissable = dismissable;
+		self.dismissable = Some(dismissable);
 		self
 	}
 
-	/// Sets the progress flag of the toast to show or hide the progress bar.
+	/// Sets whether clicking the toast dismisses it. Clicks only dismiss toasts that are
+	/// also dismissable.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_dismiss_on_click(false); // keeps the toast on screen when clicked.
+	/// ```
+	#[must_use]
+	pub fn with_dismiss_on_click(mut self, dismiss_on_click: bool) -> Self {
+		self.dismiss_on_click = dismiss_on_click;
+		self
+	}
+
+	/// Sets the acknowledge flag of the toast to show or hide a dismiss button. The
+	/// button dismisses the toast regardless of the dismissable flag.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_acknowledge(true); // shows a dismiss button on the toast.
+	/// ```
+	#[must_use]
+	pub fn with_acknowledge(mut self, acknowledge: bool) -> Self {
+		self.acknowledge = acknowledge;
+		self
+	}
+
+	/// Sets the click-through flag of the toast. Click-through toasts let pointer
+	/// events pass to the content underneath them, so they cannot be dismissed on
+	/// click but still expire on their timer.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_click_through(true); // clicks reach the content under the toast.
+	/// ```
+	#[must_use]
+	pub fn with_click_through(mut self, click_through: bool) -> Self {
+		self.click_through = click_through;
+		self
+	}
+
+	/// Sets the progress flag of the toast to show or hide the progress bar. The
+	/// progress bar is only shown on toasts with an expiry.
 	///
 	/// # Examples
 	/// ```
@@ -103,7 +368,156 @@ impl ToastBuilder {
 	/// ```
 	#[must_use]
 	pub fn with_progress(mut self, progress: bool) -> Self {
-		self.progress = progress;
+		self.progress = Some(progress);
+		self
+	}
+
+	/// Sets custom content that is rendered inside the progress bar, on top of the
+	/// animating fill. Increase `--leptoaster-progress-height` to make room for it.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_progress_content(view! { <small>"Closing soon"</small> });
+	/// ```
+	#[must_use]
+	pub fn with_progress_content(mut self, content: impl IntoView + Clone + 'static) -> Self {
+		self.progress_content = Some(ViewFn::from(move || content.clone()));
+		self
+	}
+
+	/// Sets whether the progress bar of the toast shrinks or grows as the toast expires.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_progress_direction(ProgressDirection::Grow); // fills the progress bar up.
+	/// ```
+	#[must_use]
+	pub fn with_progress_direction(mut self, progress_direction: ProgressDirection) -> Self {
+		self.progress_direction = progress_direction;
+		self
+	}
+
+	/// Sets whether the progress bar of the toast is pinned to its top or bottom edge.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_progress_position(ProgressPosition::Top); // pins the progress bar to the top.
+	/// ```
+	#[must_use]
+	pub fn with_progress_position(mut self, progress_position: ProgressPosition) -> Self {
+		self.progress_position = progress_position;
+		self
+	}
+
+	/// Reveals the message of the toast one character at a time, waiting the supplied
+	/// number of milliseconds between each character. The expiry of the toast only
+	/// starts once the full message is revealed. Users who prefer reduced motion see
+	/// the full message immediately.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_typewriter(40); // reveals a character every `40ms`.
+	/// ```
+	#[must_use]
+	pub fn with_typewriter(mut self, speed: u32) -> Self {
+		self.typewriter = Some(speed);
+		self
+	}
+
+	/// Clamps the message of the toast to the supplied number of lines. When the
+	/// message is longer, a toggle is shown that expands the toast to show the full
+	/// message and collapses it again.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My very long toast message.")
+	///     .with_max_lines(2); // shows at most two lines of the message.
+	/// ```
+	#[must_use]
+	pub fn with_max_lines(mut self, max_lines: u32) -> Self {
+		self.max_lines = Some(max_lines);
+		self
+	}
+
+	/// Sets the multiline flag of the toast. Multiline toasts render line breaks in
+	/// their message and wrap long lines instead of cutting them off with an ellipsis.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("The upload failed.\nCheck your connection and try again.")
+	///     .with_multiline(true); // renders the message on two lines.
+	/// ```
+	#[must_use]
+	pub fn with_multiline(mut self, multiline: bool) -> Self {
+		self.multiline = multiline;
+		self
+	}
+
+	/// Sets the minimizable flag of the toast to show or hide a control that collapses
+	/// the toast into a compact chip. Clicking the chip expands the toast again.
+	///
+	/// Minimized toasts do not expire until they are expanded, so this is best suited
+	/// to toasts without an expiry.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_expiry(None)
+	///     .with_minimizable(true); // allows the toast to be minimized.
+	/// ```
+	#[must_use]
+	pub fn with_minimizable(mut self, minimizable: bool) -> Self {
+		self.minimizable = minimizable;
+		self
+	}
+
+	/// Sets the pulse flag of the toast to briefly pulse its border when it first
+	/// appears, drawing attention to important toasts. The pulse is skipped for users
+	/// who prefer reduced motion.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_pulse(true); // pulses the border of the toast on mount.
+	/// ```
+	#[must_use]
+	pub fn with_pulse(mut self, pulse: bool) -> Self {
+		self.pulse = pulse;
+		self
+	}
+
+	/// Sets the silent flag of the toast to hide it from screen readers. Use this for
+	/// purely visual toasts that duplicate information which was already announced.
+	/// Silent toasts have no live-region role unless one is set with `with_role`.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("Saved.")
+	///     .with_silent(true); // the toast is not announced by screen readers.
+	/// ```
+	#[must_use]
+	pub fn with_silent(mut self, silent: bool) -> Self {
+		self.silent = silent;
+		self
+	}
+
+	/// Sets the sticky flag of the toast. Sticky toasts are left on screen when all
+	/// toasts are cleared with `ToasterContext::clear`, but still expire and can still
+	/// be dismissed.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("Your session has expired.")
+	///     .with_expiry(None)
+	///     .with_sticky(true); // the toast survives `clear`.
+	/// ```
+	#[must_use]
+	pub fn with_sticky(mut self, sticky: bool) -> Self {
+		self.sticky = sticky;
 		self
 	}
 
@@ -116,7 +530,22 @@ impl ToastBuilder {
 	/// ```
 	#[must_use]
 	pub fn with_expiry(mut self, expiry: Option<u32>) -> Self {
-		self.expiry = expiry;
+		self.expiry = Some(expiry);
+		self
+	}
+
+	/// Sets the expiry time of the toast to the supplied duration. Durations longer
+	/// than `u32::MAX` milliseconds, about 49 days, are clamped to `u32::MAX`
+	/// milliseconds.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_expiry_duration(Duration::from_secs(3)); // sets the expiry time to `3000ms`.
+	/// ```
+	#[must_use]
+	pub fn with_expiry_duration(mut self, expiry: Duration) -> Self {
+		self.expiry = Some(Some(u32::try_from(expiry.as_millis()).unwrap_or(u32::MAX)));
 		self
 	}
 
@@ -129,26 +558,376 @@ impl ToastBuilder {
 	/// ```
 	#[must_use]
 	pub fn with_position(mut self, position: ToastPosition) -> Self {
-		self.position = position;
+		self.position = Some(position);
+		self
+	}
+
+	/// Sets the animation used to show and hide the toast.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_animation(ToastAnimation::PopSlide); // pops the toast in and slides it out.
+	/// ```
+	#[must_use]
+	pub fn with_animation(mut self, animation: ToastAnimation) -> Self {
+		self.animation = animation;
+		self
+	}
+
+	/// Sets the edge of the screen the toast slides in from and out to, instead of
+	/// the edge nearest to its position. Applies to the `Slide` animation and to the
+	/// exit of the `PopSlide` animation.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_position(ToastPosition::BottomLeft)
+	///     .with_slide_from(SlideOrigin::Bottom); // slides the toast up from the bottom.
+	/// ```
+	#[must_use]
+	pub fn with_slide_from(mut self, slide_from: SlideOrigin) -> Self {
+		self.slide_from = Some(slide_from);
+		self
+	}
+
+	/// Sets the exit animation flag of the toast. Toasts without an exit animation are
+	/// removed immediately once they are cleared.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("Copied.")
+	///     .with_exit_animation(false); // removes the toast without animating it out.
+	/// ```
+	#[must_use]
+	pub fn with_exit_animation(mut self, exit_animation: bool) -> Self {
+		self.exit_animation = exit_animation;
+		self
+	}
+
+	/// Sets the key of the toast, identifying it across calls to
+	/// `ToasterContext::replace_all`.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_key("connection-lost"); // sets the key of the toast.
+	/// ```
+	#[must_use]
+	pub fn with_key(mut self, key: &str) -> Self {
+		self.key = Some(key.into());
+		self
+	}
+
+	/// Sets the deduplication key of the toast. While a toast with the same key is
+	/// on screen, toasting again restarts that toast's expiry and increments the
+	/// count shown on it instead of adding a new toast. Toasts without a deduplication
+	/// key always add a new toast.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("The request failed.")
+	///     .with_dedup_key("request-failed"); // repeated toasts are merged into one.
+	/// ```
+	#[must_use]
+	pub fn with_dedup_key(mut self, dedup_key: &str) -> Self {
+		self.dedup_key = Some(dedup_key.into());
+		self
+	}
+
+	/// Sets the group of the toast, allowing related toasts to be queried and
+	/// dismissed together.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_group("uploads"); // adds the toast to the `uploads` group.
+	/// ```
+	#[must_use]
+	pub fn with_group(mut self, group: &str) -> Self {
+		self.group = Some(group.into());
+		self
+	}
+
+	/// Sets the tag of the toast, allowing toasts about the same subject to be found
+	/// and dismissed together. Unlike groups, tags do not affect how toasts are shown.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_tag("order-1234"); // tags the toast with `order-1234`.
+	/// ```
+	#[must_use]
+	pub fn with_tag(mut self, tag: &str) -> Self {
+		self.tag = Some(tag.into());
+		self
+	}
+
+	/// Sets the ARIA role of the toast, overriding the default role derived from the
+	/// toast's level.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_role("log"); // sets the role of the toast to `log`.
+	/// ```
+	#[must_use]
+	pub fn with_role(mut self, role: &str) -> Self {
+		self.role = Some(role.into());
+		self
+	}
+
+	/// Adds the supplied CSS class to the toast element, allowing the toast to be
+	/// targeted by external stylesheets.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_class("my-critical-toast"); // adds the `my-critical-toast` class to the toast.
+	/// ```
+	#[must_use]
+	pub fn with_class(mut self, class: &str) -> Self {
+		self.class = Some(class.into());
+		self
+	}
+
+	/// Sets the width of the toast to the supplied CSS length, overriding the width of
+	/// its container.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_width("400px"); // makes the toast 400 pixels wide.
+	/// ```
+	#[must_use]
+	pub fn with_width(mut self, width: &str) -> Self {
+		self.width = Some(width.into());
+		self
+	}
+
+	/// Sets the font size of the message to the supplied CSS length, overriding the
+	/// `--leptoaster-font-size` variable for this toast.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_font_size("18px"); // renders the message in 18 pixel text.
+	/// ```
+	#[must_use]
+	pub fn with_font_size(mut self, font_size: &str) -> Self {
+		self.font_size = Some(font_size.into());
+		self
+	}
+
+	/// Sets the font weight of the message to the supplied CSS font weight, overriding
+	/// the `--leptoaster-font-weight` variable for this toast.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_font_weight("800"); // renders the message in extra bold text.
+	/// ```
+	#[must_use]
+	pub fn with_font_weight(mut self, font_weight: &str) -> Self {
+		self.font_weight = Some(font_weight.into());
+		self
+	}
+
+	/// Adds an action button with the supplied label to the toast. Clicking the button
+	/// runs the callback and then dismisses the toast.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("The file was deleted.")
+	///     .with_action("Undo", move || restore_file());
+	/// ```
+	#[must_use]
+	pub fn with_action(mut self, label: &str, callback: impl Fn() + 'static) -> Self {
+		self.actions.push((label.into(), Rc::new(callback)));
+		self
+	}
+
+	/// Adds an action button for each of the supplied labels and callbacks, such as
+	/// confirm and cancel buttons. Clicking a button runs its callback and then
+	/// dismisses the toast. As the toast waits for one of the buttons to be clicked,
+	/// it is made non-dismissable and its expiry is disabled.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("Discard your changes?")
+	///     .with_actions(vec![
+	///         ("Discard".into(), Rc::new(move || discard_changes())),
+	///         ("Cancel".into(), Rc::new(|| {})),
+	///     ]);
+	/// ```
+	#[must_use]
+	pub fn with_actions(mut self, actions: Vec<(String, Rc<dyn Fn()>)>) -> Self {
+		self.actions.extend(actions);
+		self.dismissable = Some(false);
+		self.expiry = Some(None);
 		self
 	}
 
+	/// Sets a callback that is run when the toast is clicked. The callback runs even if
+	/// the toast is not dismissable. Whether the click also dismisses the toast is set
+	/// with `with_dismiss_on_click`.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("You have a new message.")
+	///     .with_on_click(move || navigate("/messages", Default::default()))
+	///     .with_dismiss_on_click(false); // keeps the toast on screen when clicked.
+	/// ```
+	#[must_use]
+	pub fn with_on_click(mut self, on_click: impl Fn() + 'static) -> Self {
+		self.on_click = Some(Rc::new(on_click));
+		self
+	}
+
+	/// Sets a callback that is run with the toast's ID once the toast is first rendered.
+	/// Updating, moving, minimizing or regrouping the toast does not run the callback
+	/// again.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_on_show(|id| {
+	///         logging::log!("toast {id} was shown");
+	///     });
+	/// ```
+	#[must_use]
+	pub fn with_on_show(mut self, on_show: impl Fn(ToastId) + 'static) -> Self {
+		self.on_show = Some(Rc::new(on_show));
+		self
+	}
+
+	/// Sets a callback that is run when the toast is removed after being dismissed,
+	/// receiving the toast's ID and how long the toast was on screen. Time spent
+	/// while the toast was paused is not counted.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_on_removed(|id, duration| {
+	///         logging::log!("toast {id} was shown for {duration:?}");
+	///     });
+	/// ```
+	#[must_use]
+	pub fn with_on_removed(mut self, on_removed: impl Fn(ToastId, Duration) + 'static) -> Self {
+		self.on_removed = Some(Rc::new(on_removed));
+		self
+	}
+
+	/// Sets a callback that is run with the toast's ID when the toast is removed from
+	/// the queue, whether it expired, was clicked or was cleared programmatically.
+	/// The callback runs exactly once per toast.
+	///
+	/// # Examples
+	/// ```
+	/// ToastBuilder::new("My toast message.")
+	///     .with_on_dismiss(|id| {
+	///         logging::log!("toast {id} was dismissed");
+	///     });
+	/// ```
+	#[must_use]
+	pub fn with_on_dismiss(mut self, on_dismiss: impl Fn(ToastId) + 'static) -> Self {
+		self.on_dismiss = Some(Rc::new(on_dismiss));
+		self
+	}
+
+	/// Returns the key of the toast, if any.
+	pub(crate) fn key(&self) -> Option<&str> {
+		self.key.as_deref()
+	}
+
+	/// Returns the deduplication key of the toast, if any.
+	pub(crate) fn dedup_key(&self) -> Option<&str> {
+		self.dedup_key.as_deref()
+	}
+
 	/// Builds the toast into a `ToastData` with the supplied ID.
+	///
+	/// Unless explicitly set, the progress bar is disabled on toasts without an expiry.
 	#[must_use]
 	pub fn build(self, id: ToastId) -> ToastData {
+		self.build_with_config(id, &ToasterConfig::default())
+	}
+
+	/// Builds the toast into a `ToastData` with the supplied ID, using the defaults of
+	/// the supplied config for any of its fields that were not explicitly set.
+	pub(crate) fn build_with_config(self, id: ToastId, config: &ToasterConfig) -> ToastData {
+		let loading = self.level == ToastLevel::Loading;
+
+		let expiry = self.expiry.unwrap_or_else(|| {
+			config.level_expiry.get(&self.level).copied().unwrap_or(match loading {
+				true => None,
+				false => config.expiry,
+			})
+		});
+
+		let progress = match (self.progress, expiry) {
+			(Some(true), None) => {
+				logging::debug_warn!("leptoaster: the progress bar is not shown on toasts without an expiry");
+				false
+			},
+
+			(Some(progress), _) => progress,
+			(None, expiry) => config.progress && expiry.is_some(),
+		};
+
 		ToastData {
 			id,
+			title: self.title,
 			message: self.message,
+			progress_message: self.progress_message,
+			body: self.body,
+			html: self.html,
 
 			level: self.level,
 
-			dismissable: self.dismissable,
-			expiry: self.expiry,
-			progress: self.progress,
+			dismissable: self.dismissable.unwrap_or(config.dismissable && !loading),
+			dismiss_on_click: self.dismiss_on_click,
+			acknowledge: self.acknowledge,
+			click_through: self.click_through,
+			expiry,
+			progress,
+			progress_content: self.progress_content,
+			progress_direction: self.progress_direction,
+			progress_position: self.progress_position,
+			typewriter: self.typewriter,
+			max_lines: self.max_lines,
+			multiline: self.multiline,
+			minimizable: self.minimizable,
+			pulse: self.pulse,
+			silent: self.silent,
+			sticky: self.sticky,
 
-			position: self.position,
+			position: self.position.unwrap_or_else(|| config.position.clone()),
+			animation: self.animation,
+			slide_from: self.slide_from,
+			exit_animation: self.exit_animation,
+
+			key: self.key,
+			dedup_key: self.dedup_key,
+			group: self.group,
+			tag: self.tag,
+			role: self.role,
+			class: self.class,
+			width: self.width,
+			font_size: self.font_size,
+			font_weight: self.font_weight,
 
 			clear_signal: create_rw_signal(false),
+			pause_signal: create_rw_signal(false),
+			minimized: create_rw_signal(false),
+			repeat_count: create_rw_signal(1),
+			shown: create_rw_signal(false),
+
+			actions: self.actions,
+			on_click: self.on_click,
+			on_show: self.on_show,
+			on_removed: self.on_removed,
+			on_dismiss: self.on_dismiss,
 		}
 	}
 }
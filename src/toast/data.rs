@@ -5,39 +5,255 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::{
+	fmt,
+	rc::Rc,
+	time::Duration,
+	hash::{Hash, Hasher},
+};
+
 use leptos::*;
 
 pub type ToastId = u64;
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ToastLevel {
 	Info,
 	Success,
 	Warn,
 	Error,
+
+	/// A level for pending operations, rendered with a spinner. Loading toasts are
+	/// usually created with `ToastBuilder::loading` and later changed to another
+	/// level with `ToasterContext::update`.
+	Loading,
+
+	/// A level with caller-supplied colors. Each color can be any CSS color value,
+	/// including a `var(...)` reference to a custom property.
+	Custom {
+		background: String,
+		border: String,
+		text: String,
+	},
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ToastPosition {
 	TopLeft,
+	TopCenter,
 	TopRight,
 	BottomRight,
+	BottomCenter,
 	BottomLeft,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ToastAnimation {
+	/// Slides the toast in from and out to the nearest edge.
+	#[default]
+	Slide,
+
+	/// Pops the toast in from its center and slides it out to the nearest edge.
+	PopSlide,
+
+	/// Bounces the toast in from a smaller scale and shrinks it out in place, which
+	/// draws more attention than sliding.
+	Pop,
+
+	/// Fades the toast in and out in place.
+	Fade,
+}
+
+/// Defines the edge of the screen a sliding toast slides in from and out to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SlideOrigin {
+	Left,
+	Right,
+	Top,
+	Bottom,
+}
+
+/// Defines how the progress bar of a toast moves as the toast expires.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProgressDirection {
+	/// Shrinks the progress bar from full width to empty.
+	#[default]
+	Shrink,
+
+	/// Grows the progress bar from empty to full width.
+	Grow,
+}
+
+/// Defines which edge of a toast its progress bar is pinned to.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProgressPosition {
+	/// Pins the progress bar to the top edge of the toast.
+	Top,
+
+	/// Pins the progress bar to the bottom edge of the toast.
+	#[default]
+	Bottom,
+}
+
+/// Defines how toasts narrower than their container line up within the center
+/// containers.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ToastAlign {
+	/// Aligns the toasts with the start edge of the container.
+	Start,
+
+	/// Centers the toasts within the container.
+	#[default]
+	Center,
+
+	/// Aligns the toasts with the end edge of the container.
+	End,
+}
+
+/// Defines what happens to toasts whose position is not rendered by the `Toaster`.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum PositionFallback {
+	/// Redirects the toast to the nearest rendered position.
+	#[default]
+	Nearest,
+
+	/// Drops the toast.
+	Drop,
+}
+
+/// A toast in the toaster queue.
+///
+/// Toasts have identity semantics: equality and hashing are based on the toast's
+/// `id` only, so a `ToastData` can be stored in a `HashSet` or compared against
+/// another snapshot of the same toast regardless of its other fields.
+///
+/// With the `serde` feature enabled, toasts can be serialized and deserialized.
+/// Signals, views and callbacks are skipped, and deserialized toasts start with
+/// fresh signals.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToastData {
 	pub id: ToastId,
 
+	pub title: Option<String>,
 	pub message: String,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub progress_message: Option<Signal<String>>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub body: Option<ViewFn>,
+	pub html: Option<String>,
 
 	pub level: ToastLevel,
 
 	pub dismissable: bool,
+	pub dismiss_on_click: bool,
+	pub acknowledge: bool,
+	pub click_through: bool,
 	pub expiry: Option<u32>,
 	pub progress: bool,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub progress_content: Option<ViewFn>,
+	pub progress_direction: ProgressDirection,
+	pub progress_position: ProgressPosition,
+	pub typewriter: Option<u32>,
+	pub max_lines: Option<u32>,
+	pub multiline: bool,
+	pub minimizable: bool,
+	pub pulse: bool,
+	pub silent: bool,
+	pub sticky: bool,
 
 	pub position: ToastPosition,
+	pub animation: ToastAnimation,
+	pub slide_from: Option<SlideOrigin>,
+	pub exit_animation: bool,
+
+	pub key: Option<String>,
+	pub dedup_key: Option<String>,
+	pub group: Option<String>,
+	pub tag: Option<String>,
+	pub role: Option<String>,
+	pub class: Option<String>,
+	pub width: Option<String>,
+	pub font_size: Option<String>,
+	pub font_weight: Option<String>,
 
+	#[cfg_attr(feature = "serde", serde(skip, default = "new_flag_signal"))]
 	pub clear_signal: RwSignal<bool>,
+	#[cfg_attr(feature = "serde", serde(skip, default = "new_flag_signal"))]
+	pub pause_signal: RwSignal<bool>,
+	#[cfg_attr(feature = "serde", serde(skip, default = "new_flag_signal"))]
+	pub minimized: RwSignal<bool>,
+	#[cfg_attr(feature = "serde", serde(skip, default = "new_repeat_count_signal"))]
+	pub repeat_count: RwSignal<u32>,
+	#[cfg_attr(feature = "serde", serde(skip, default = "new_flag_signal"))]
+	pub(crate) shown: RwSignal<bool>,
+
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub actions: Vec<(String, Rc<dyn Fn()>)>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub on_click: Option<Rc<dyn Fn()>>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub on_show: Option<Rc<dyn Fn(ToastId)>>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub on_removed: Option<Rc<dyn Fn(ToastId, Duration)>>,
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub on_dismiss: Option<Rc<dyn Fn(ToastId)>>,
+}
+
+#[cfg(feature = "serde")]
+fn new_flag_signal() -> RwSignal<bool> {
+	create_rw_signal(false)
+}
+
+#[cfg(feature = "serde")]
+fn new_repeat_count_signal() -> RwSignal<u32> {
+	create_rw_signal(1)
+}
+
+impl ToastData {
+	/// Returns a signal that is `true` while the toast's expiry timer is paused.
+	/// Setting the toast's `pause_signal` pauses and resumes the timer.
+	#[must_use]
+	pub fn paused(&self) -> Signal<bool> {
+		self.pause_signal.into()
+	}
+}
+
+impl fmt::Debug for ToastData {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ToastData")
+			.field("id", &self.id)
+			.field("title", &self.title)
+			.field("message", &self.message)
+			.field("level", &self.level)
+			.field("dismissable", &self.dismissable)
+			.field("expiry", &self.expiry)
+			.field("progress", &self.progress)
+			.field("position", &self.position)
+			.field("key", &self.key)
+			.field("group", &self.group)
+			.field("tag", &self.tag)
+			.finish_non_exhaustive()
+	}
+}
+
+impl PartialEq for ToastData {
+	fn eq(&self, other: &Self) -> bool {
+		self.id == other.id
+	}
+}
+
+impl Eq for ToastData {}
+
+impl Hash for ToastData {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.id.hash(state);
+	}
 }
@@ -6,21 +6,135 @@
  */
 
 pub mod context;
+mod labels;
+mod event;
+mod config;
 
+use std::cell::RefCell;
 use leptos::*;
+use leptos::wasm_bindgen::JsCast;
 use crate::toaster::context::ToasterContext;
-use crate::toast::{Toast, ToastData, ToastPosition};
+use crate::toast::{
+	Toast,
+	ToastChip,
+	ToastData,
+	ToastId,
+	ToastPosition,
+	PositionFallback,
+	ToastAlign,
+	get_physical_position,
+};
+
+pub use crate::toaster::labels::ToastLabels;
+pub use crate::toaster::event::ToastEvent;
+pub use crate::toaster::config::{ToasterConfig, OverflowPolicy};
+
+thread_local! {
+	static GLOBAL_TOASTER: RefCell<Option<ToasterContext>> = const { RefCell::new(None) };
+}
 
 const CONTAINER_POSITIONS: &[ToastPosition] = &[
 	ToastPosition::TopLeft,
+	ToastPosition::TopCenter,
 	ToastPosition::TopRight,
 	ToastPosition::BottomRight,
+	ToastPosition::BottomCenter,
 	ToastPosition::BottomLeft,
 ];
 
-/// Creates the toaster containers as fixed-position elements on the corners of the screen.
+/// Creates the toaster containers as fixed-position elements on the corners and the
+/// top and bottom centers of the screen.
+///
+/// Takes an optional prop that defines whether or not the toasts are stacked, and
+/// an optional set of `ToastLabels` used for any text rendered by the toasts.
+///
+/// The optional `max_enter_animations` prop limits how many toasts animate in at the
+/// same time, which avoids jank when many toasts appear at once.
+///
+/// The optional `max_visible` prop limits how many toasts are visible in each
+/// position. Once a new toast exceeds the limit, the oldest toasts in its position
+/// are cleared.
+///
+/// The optional `stack_depth` prop sets how many toasts are shown in each stack when
+/// the toasts are stacked, and defaults to 5. Toasts beyond the depth are hidden until
+/// the stack is hovered. On touch screens, tapping a toast expands its stack and
+/// tapping again collapses it. A depth below one is raised to one.
+///
+/// The optional `gap_px` prop sets the vertical gap between toasts, which is 12 pixels
+/// by default. The offset between stacked toasts follows the gap.
+///
+/// The optional `offset_top`, `offset_bottom`, and `offset_x` props take CSS lengths
+/// that move the containers away from the top, bottom, and side edges of the screen,
+/// such as to keep the toasts clear of a fixed header. All offsets default to 0.
+///
+/// The optional `animation_duration_ms` prop sets how long the enter and exit
+/// animations of the toasts take, and defaults to 200 milliseconds. Durations below
+/// one millisecond are raised to one millisecond so a cleared toast always gets to
+/// render its exit animation before it is removed.
+///
+/// When the optional `target_id` prop is supplied and an element with that id exists
+/// when the `Toaster` is mounted, the containers are rendered into that element
+/// instead of being fixed to the corners of the screen, leaving their placement to
+/// the application's layout. The optional `mount` prop does the same with an element
+/// the application already holds, and takes precedence over `target_id`.
+///
+/// The optional `align` prop lines up toasts narrower than their container, such as
+/// toasts with a custom width, within the top and bottom center containers. Toasts
+/// are centered by default.
+///
+/// The optional `show_icons` prop renders an icon matching the level of each toast
+/// before its message.
+///
+/// The optional `show_close_button` prop renders a close button on each toast that
+/// dismisses it even if the toast is not dismissable by clicking.
+///
+/// The optional `pause_on_hover` prop pauses the expiry timer and progress bar of a
+/// toast while the pointer is over it.
+///
+/// The optional `swipe_to_dismiss` prop lets dismissable toasts be swiped away
+/// sideways, which is natural on touch devices.
+///
+/// The optional `auto_dark` prop switches the toasts to darker colors when the user
+/// prefers a dark color scheme. These colors have no specificity, so any of the CSS
+/// variables set by the application still take precedence.
+///
+/// The optional `info_bg`, `info_border`, and `info_text` props, along with their
+/// `success`, `warn`, and `error` counterparts, set the background, border, and text
+/// colors of each level. They replace the default colors, including the darker colors
+/// of `auto_dark`, while CSS variables set by the application still take precedence.
 ///
-/// Takes an optional prop that defines whether or not the toasts are stacked.
+/// The optional `border_radius` and `padding` props set the corner radius and inner
+/// padding of each toast, which are `4px` and `16px` by default. Both accept any CSS
+/// length.
+///
+/// The optional `z_index` prop sets the z-index of the containers, which is 9999 by
+/// default. Stacked toasts are layered just below it. Each container carries a
+/// `data-leptoaster-position` attribute, such as `top_right`, so the
+/// `--leptoaster-z-index` variable can also be overridden for a single position.
+///
+/// The optional `rtl` prop lays the toasts out from right to left, mirroring the left
+/// and right containers and reversing the content of each toast.
+///
+/// The optional `dismiss_on_escape` prop dismisses the newest dismissable toast when
+/// the `Escape` key is pressed.
+///
+/// When the optional `collapse_groups` prop is set, the toasts of a group in each
+/// container collapse into the newest toast of the group, which shows the number of
+/// toasts in the group. Clicking that count expands the group. Collapsed toasts do
+/// not expire until they are shown.
+///
+/// By default, the newest toast in each container is shown nearest to the edge of the
+/// screen. The optional `newest_on_top` prop instead shows the newest toast at the
+/// top of every container when `true`, or at the bottom when `false`. Stacked toasts
+/// always keep the toast nearest to the edge of the screen in front.
+///
+/// When the optional `keyboard_navigable` prop is set, the toasts in each container
+/// form a single tab stop. The arrow keys move focus between the toasts and the
+/// `Delete` and `Backspace` keys dismiss the focused toast.
+///
+/// The rendered containers can be limited with the optional `positions` prop. Toasts
+/// targeting a position that is not rendered are handled by `position_fallback`,
+/// which redirects them to the nearest rendered position by default.
 ///
 /// # Examples
 /// ```
@@ -30,7 +144,10 @@ const CONTAINER_POSITIONS: &[ToastPosition] = &[
 /// #[component]
 /// fn App() -> impl IntoView {
 ///     view! {
-///         <Toaster stacked={true} />
+///         <Toaster
+///             stacked={true}
+///             positions={vec![ToastPosition::BottomLeft, ToastPosition::BottomRight]}
+///         />
 ///     }
 /// }
 /// ```
@@ -38,13 +155,332 @@ const CONTAINER_POSITIONS: &[ToastPosition] = &[
 pub fn Toaster(
 	#[prop(optional, into)]
 	stacked: MaybeSignal<bool>,
+
+	#[prop(optional)]
+	max_enter_animations: Option<u32>,
+
+	#[prop(optional)]
+	max_visible: Option<usize>,
+
+	#[prop(default = 5)]
+	stack_depth: u8,
+
+	#[prop(optional)]
+	gap_px: Option<u32>,
+
+	#[prop(into, default = "0".into())]
+	offset_top: String,
+
+	#[prop(into, default = "0".into())]
+	offset_bottom: String,
+
+	#[prop(into, default = "0".into())]
+	offset_x: String,
+
+	#[prop(default = 200)]
+	animation_duration_ms: u32,
+
+	#[prop(optional)]
+	positions: Option<Vec<ToastPosition>>,
+
+	#[prop(optional)]
+	position_fallback: PositionFallback,
+
+	#[prop(optional)]
+	target_id: Option<String>,
+
+	#[prop(optional)]
+	mount: Option<web_sys::Element>,
+
+	#[prop(optional)]
+	align: ToastAlign,
+
+	#[prop(optional)]
+	keyboard_navigable: bool,
+
+	#[prop(optional)]
+	dismiss_on_escape: bool,
+
+	#[prop(optional)]
+	collapse_groups: bool,
+
+	#[prop(optional)]
+	newest_on_top: Option<bool>,
+
+	#[prop(optional)]
+	show_icons: bool,
+
+	#[prop(optional)]
+	show_close_button: bool,
+
+	#[prop(optional)]
+	pause_on_hover: bool,
+
+	#[prop(optional)]
+	swipe_to_dismiss: bool,
+
+	#[prop(optional)]
+	auto_dark: bool,
+
+	#[prop(optional, into)]
+	info_bg: Option<String>,
+
+	#[prop(optional, into)]
+	info_border: Option<String>,
+
+	#[prop(optional, into)]
+	info_text: Option<String>,
+
+	#[prop(optional, into)]
+	success_bg: Option<String>,
+
+	#[prop(optional, into)]
+	success_border: Option<String>,
+
+	#[prop(optional, into)]
+	success_text: Option<String>,
+
+	#[prop(optional, into)]
+	warn_bg: Option<String>,
+
+	#[prop(optional, into)]
+	warn_border: Option<String>,
+
+	#[prop(optional, into)]
+	warn_text: Option<String>,
+
+	#[prop(optional, into)]
+	error_bg: Option<String>,
+
+	#[prop(optional, into)]
+	error_border: Option<String>,
+
+	#[prop(optional, into)]
+	error_text: Option<String>,
+
+	#[prop(optional, into)]
+	border_radius: Option<String>,
+
+	#[prop(optional, into)]
+	padding: Option<String>,
+
+	#[prop(optional)]
+	z_index: Option<i32>,
+
+	#[prop(optional)]
+	rtl: bool,
+
+	#[prop(optional)]
+	labels: ToastLabels,
 ) -> impl IntoView {
 	let toaster = expect_toaster();
+	let positions = store_value(positions);
+
+	if max_visible.is_some() {
+		toaster.set_max_visible(max_visible);
+	}
+
+	let animation_duration = animation_duration_ms.max(1);
+	let stack_depth = stack_depth.max(1);
+
+	let target = mount.or_else(|| target_id.and_then(|target_id| document().get_element_by_id(&target_id)));
+	let fixed = target.is_none();
+
+	let region_label = store_value(labels.region.clone());
+	let offsets = store_value((offset_top, offset_bottom, offset_x));
+	let expanded_groups = collapse_groups.then(|| create_rw_signal(Vec::<String>::new()));
+
+	let variable_overrides = get_variable_overrides(&[
+		("info-background-color", info_bg),
+		("info-border-color", info_border),
+		("info-text-color", info_text),
+		("success-background-color", success_bg),
+		("success-border-color", success_border),
+		("success-text-color", success_text),
+		("warn-background-color", warn_bg),
+		("warn-border-color", warn_border),
+		("warn-text-color", warn_text),
+		("error-background-color", error_bg),
+		("error-border-color", error_border),
+		("error-text-color", error_text),
+		("border-radius", border_radius),
+		("padding", padding),
+		("z-index", z_index.map(|z_index| z_index.to_string())),
+	]);
+
+	provide_context(labels);
+
+	let queue = match positions.with_value(Option::is_some) {
+		true => {
+			let rendered = create_rw_signal(Vec::new());
+
+			create_effect({
+				let toaster = toaster.clone();
+
+				move |_| rendered.set(toaster.queue.with(|queue| positions.with_value(|positions| {
+					get_rendered_toasts(queue, positions, position_fallback)
+				})))
+			});
+
+			Signal::from(rendered)
+		},
+
+		false => Signal::from(toaster.queue),
+	};
+
+	if position_fallback == PositionFallback::Drop {
+		create_effect(move |_| {
+			let dropped = toaster.queue.with(|queue| {
+				queue.iter()
+					.filter(|toast| positions.with_value(|positions| {
+						get_rendered_position(&toast.position, positions, position_fallback).is_none()
+					}))
+					.map(|toast| toast.id)
+					.collect::<Vec<ToastId>>()
+			});
+
+			for toast_id in dropped {
+				toaster.remove(toast_id);
+			}
+		});
+	}
+
+	if dismiss_on_escape {
+		let handle = window_event_listener(ev::keydown, move |event| {
+			if event.key() != "Escape" {
+				return;
+			}
+
+			let newest = queue.with_untracked(|queue| {
+				queue.iter()
+					.rev()
+					.find(|toast| toast.dismissable && !toast.clear_signal.get_untracked())
+					.map(|toast| toast.clear_signal)
+			});
+
+			if let Some(clear_signal) = newest {
+				clear_signal.set(true);
+			}
+		});
+
+		on_cleanup(move || handle.remove());
+	}
+
+	let containers = move || view! {
+		<For
+			each=move || {
+				CONTAINER_POSITIONS.iter()
+					.filter(|position| positions.with_value(|positions| is_position_enabled(position, positions)))
+					.collect::<Vec<&ToastPosition>>()
+			}
+			key=|position| get_container_id(position)
+			let:position
+		>
+			{
+				let keyboard_focus = keyboard_navigable.then(|| create_rw_signal(None::<ToastId>));
+				let stack_expanded = create_rw_signal(false);
+
+				if let Some(keyboard_focus) = keyboard_focus {
+					create_effect(move |_| {
+						let toasts = get_container_toasts(queue, position, expanded_groups, newest_on_top);
+
+						let focused = keyboard_focus.get_untracked()
+							.filter(|toast_id| toasts.iter().any(|toast| toast.id == *toast_id));
+
+						if focused.is_none() {
+							keyboard_focus.set(toasts.first().map(|toast| toast.id));
+						}
+					});
+				}
+
+				view! {
+					<Show
+						when=move || !is_container_empty(queue, position)
+					>
+						<div
+							class=get_container_class(stacked(), position)
+							class:leptoaster-stack-expanded=move || stack_expanded.get()
+							class:leptoaster-aligned-container=is_center_position(position)
+							style:width=fixed.then_some("var(--leptoaster-width)")
+							style:max-width=fixed.then_some("var(--leptoaster-max-width)")
+							role="region"
+							data-leptoaster-position=get_container_id(position)
+							aria-label=move || region_label.get_value()
+							dir=rtl.then_some("rtl")
+							style:margin=fixed.then(|| get_container_margin(&get_physical_position(position, rtl)))
+							style:position=fixed.then_some("fixed")
+							style:inset=fixed.then(|| offsets.with_value(|(top, bottom, x)| {
+								get_container_inset(&get_physical_position(position, rtl), top, bottom, x)
+							}))
+							style:transform=fixed.then(|| get_container_transform(position)).flatten()
+							style:z-index=fixed.then_some("var(--leptoaster-z-index)")
+							style:display=is_center_position(position).then_some("flex")
+							style:flex-direction=is_center_position(position).then_some("column")
+							style:align-items=is_center_position(position).then(|| get_align_items(align))
+							on:keydown=move |event| {
+								if let Some(keyboard_focus) = keyboard_focus {
+									handle_keyboard_navigation(event, keyboard_focus, queue, position, expanded_groups, newest_on_top);
+								}
+							}
+						>
+							<Show
+								when=move || is_bottom_position(position)
+							>
+								<ToastChips position={position} queue={queue} />
+							</Show>
+
+							<For
+								each=move || get_container_toasts(queue, position, expanded_groups, newest_on_top)
+								key=|toast| toast.id
+								let:toast
+							>
+								{
+									let toast_id = toast.id;
+									let group = toast.group.clone();
+
+									let group_count = Signal::derive(move || {
+										get_group_count(queue, position, toast_id, group.as_deref())
+									});
+
+									view! {
+										<Toast
+											toast={toast}
+											max_enter_animations={max_enter_animations}
+											animation_duration={animation_duration}
+											show_icons={show_icons}
+											show_close_button={show_close_button}
+											pause_on_hover={pause_on_hover}
+											swipe_to_dismiss={swipe_to_dismiss}
+											rtl={rtl}
+											keyboard_focus={keyboard_focus}
+											group_count={group_count}
+											expanded_groups={expanded_groups}
+											stack_expanded={stacked.get_untracked().then_some(stack_expanded)}
+										/>
+									}
+								}
+							</For>
+
+							<Show
+								when=move || !is_bottom_position(position)
+							>
+								<ToastChips position={position} queue={queue} />
+							</Show>
+						</div>
+					</Show>
+				}
+			}
+		</For>
+	};
 
 	view! {
+		<style>
+			{get_stack_styles(stack_depth)}
+		</style>
+
 		<style>
 			"
-			:root {
+			:where(:root) {
 				--leptoaster-width: 320px;
 				--leptoaster-max-width: 80vw;
 				--leptoaster-z-index: 9999;
@@ -54,6 +490,15 @@ pub fn Toaster(
 				--leptoaster-line-height: 20px;
 				--leptoaster-font-weight: 600;
 
+				--leptoaster-title-font-size: 16px;
+				--leptoaster-title-font-weight: 700;
+
+				--leptoaster-border-radius: 4px;
+				--leptoaster-padding: 16px;
+
+				--leptoaster-gap: 12px;
+				--leptoaster-stack-step: calc(50px + var(--leptoaster-gap));
+
 				--leptoaster-progress-height: 2px;
 
 				--leptoaster-info-background-color: #ffffff;
@@ -71,148 +516,219 @@ pub fn Toaster(
 				--leptoaster-error-background-color: #f44336;
 				--leptoaster-error-border-color: #c62828;
 				--leptoaster-error-text-color: #ffffff;
+
+				--leptoaster-loading-background-color: #f5f5f5;
+				--leptoaster-loading-border-color: #9e9e9e;
+				--leptoaster-loading-text-color: #222222;
 			}
 
 			.leptoaster-stack-container-bottom:hover > div,
-			.leptoaster-stack-container-top:hover > div {
+			.leptoaster-stack-container-top:hover > div,
+			.leptoaster-stack-expanded > div {
 				opacity: 1 !important;
 				transform: translateY(0) scaleX(1) !important;
 				transition-delay: 0s !important;
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(1),
-			.leptoaster-stack-container-top > div:nth-child(1) {
-				z-index: 9999;
+			.leptoaster-aligned-container > [data-leptoaster-id] + [data-leptoaster-id] {
+				margin-top: 0 !important;
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(2),
-			.leptoaster-stack-container-top > div:nth-child(2) {
-				z-index: 9998;
+			.leptoaster-stack-container-bottom > div:hover,
+			.leptoaster-stack-container-top > div:hover {
+				z-index: calc(var(--leptoaster-z-index) + 1);
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(2) {
-				transform: translateY(62px) scaleX(0.98);
+			@keyframes leptoaster-slide-in-left {
+				from { left: calc((var(--leptoaster-width) + 12px * 2) * -1) }
+				to { left: 0 }
 			}
 
-			.leptoaster-stack-container-top > div:nth-child(2) {
-				transform: translateY(-62px) scaleX(0.98);
+			@keyframes leptoaster-slide-out-left {
+				from { left: 0 }
+				to { left: calc((var(--leptoaster-width) + 12px * 2) * -1) }
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(3),
-			.leptoaster-stack-container-top > div:nth-child(3) {
-				z-index: 9997;
+			@keyframes leptoaster-slide-in-right {
+				from { right: calc((var(--leptoaster-width) + 12px * 2) * -1) }
+				to { right: 0 }
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(3) {
-				transform: translateY(124px) scaleX(0.96);
+			@keyframes leptoaster-slide-out-right {
+				from { right: 0 }
+				to { right: calc((var(--leptoaster-width) + 12px * 2) * -1) }
 			}
 
-			.leptoaster-stack-container-top > div:nth-child(3) {
-				transform: translateY(-124px) scaleX(0.96);
+			@keyframes leptoaster-slide-in-top {
+				from { top: -96px; opacity: 0; }
+				to { top: 0; opacity: 1; }
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(4),
-			.leptoaster-stack-container-top > div:nth-child(4) {
-				z-index: 9996;
+			@keyframes leptoaster-slide-out-top {
+				from { top: 0; opacity: 1; }
+				to { top: -96px; opacity: 0; }
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(4) {
-				transform: translateY(186px) scaleX(0.94);
+			@keyframes leptoaster-slide-in-bottom {
+				from { top: 96px; opacity: 0; }
+				to { top: 0; opacity: 1; }
 			}
 
-			.leptoaster-stack-container-top > div:nth-child(4) {
-				transform: translateY(-186px) scaleX(0.94);
+			@keyframes leptoaster-slide-out-bottom {
+				from { top: 0; opacity: 1; }
+				to { top: 96px; opacity: 0; }
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(5),
-			.leptoaster-stack-container-top > div:nth-child(5) {
-				z-index: 9995;
+			@keyframes leptoaster-spin {
+				to { transform: rotate(360deg); }
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(5) {
-				transform: translateY(248px) scaleX(0.92);
+			.leptoaster-spinner {
+				animation: leptoaster-spin 800ms linear infinite;
 			}
 
-			.leptoaster-stack-container-top > div:nth-child(5) {
-				transform: translateY(-248px) scaleX(0.92);
+			@keyframes leptoaster-fade-in {
+				from { opacity: 0; }
+				to { opacity: 1; }
 			}
 
-			.leptoaster-stack-container-bottom > div:nth-last-child(n+6),
-			.leptoaster-stack-container-top > div:nth-child(n+6) {
-				opacity: 0;
+			@keyframes leptoaster-fade-out {
+				from { opacity: 1; }
+				to { opacity: 0; }
 			}
 
-			@keyframes leptoaster-slide-in-left {
-				from { left: calc((var(--leptoaster-width) + 12px * 2) * -1) }
-				to { left: 0 }
+			@keyframes leptoaster-pop-in {
+				0% { scale: 0.5; }
+				70% { scale: 1.04; }
+				100% { scale: 1; }
 			}
 
-			@keyframes leptoaster-slide-out-left {
-				from { left: 0 }
-				to { left: calc((var(--leptoaster-width) + 12px * 2) * -1) }
+			@keyframes leptoaster-bounce-in {
+				0% { scale: 0.8; opacity: 0; }
+				60% { scale: 1.06; opacity: 1; }
+				80% { scale: 0.98; }
+				100% { scale: 1; opacity: 1; }
 			}
 
-			@keyframes leptoaster-slide-in-right {
-				from { right: calc((var(--leptoaster-width) + 12px * 2) * -1) }
-				to { right: 0 }
-			}
-
-			@keyframes leptoaster-slide-out-right {
-				from { right: 0 }
-				to { right: calc((var(--leptoaster-width) + 12px * 2) * -1) }
+			@keyframes leptoaster-pop-out {
+				from { scale: 1; opacity: 1; }
+				to { scale: 0.8; opacity: 0; }
 			}
 
 			@keyframes leptoaster-progress {
 				from { width: 100%; }
 				to { width: 0; }
 			}
+
+			@keyframes leptoaster-progress-grow {
+				from { width: 0; }
+				to { width: 100%; }
+			}
+
+			@keyframes leptoaster-pulse {
+				0% { box-shadow: inset 0 0 0 0 currentColor; opacity: 0; }
+				30% { box-shadow: inset 0 0 0 3px currentColor; opacity: 1; }
+				100% { box-shadow: inset 0 0 0 3px currentColor; opacity: 0; }
+			}
+
+			.leptoaster-pulse {
+				animation: leptoaster-pulse 1s ease-out forwards;
+			}
+
+			@media (prefers-reduced-motion: reduce) {
+				[data-leptoaster-id] {
+					animation-name: none !important;
+					transition: none !important;
+				}
+
+				.leptoaster-pulse {
+					animation: none;
+					opacity: 0;
+				}
+			}
 			"
 		</style>
 
-		<For
-			each=move || CONTAINER_POSITIONS
-			key=|position| get_container_id(position)
-			let:position
+		{gap_px.map(|gap_px| view! {
+			<style>
+				{format!(":where(:root) {{ --leptoaster-gap: {gap_px}px; }}")}
+			</style>
+		})}
+
+		<Show
+			when=move || auto_dark
 		>
-			<Show
-				when=move || !is_container_empty(position)
+			<style>
+				"
+				@media (prefers-color-scheme: dark) {
+					:where(:root) {
+						--leptoaster-info-background-color: #222222;
+						--leptoaster-info-border-color: #444444;
+						--leptoaster-info-text-color: #f5f5f5;
+
+						--leptoaster-success-background-color: #2e7d32;
+						--leptoaster-success-border-color: #1b5e20;
+						--leptoaster-success-text-color: #f5f5f5;
+
+						--leptoaster-warn-background-color: #ef6c00;
+						--leptoaster-warn-border-color: #e65100;
+						--leptoaster-warn-text-color: #f5f5f5;
+
+						--leptoaster-error-background-color: #c62828;
+						--leptoaster-error-border-color: #b71c1c;
+						--leptoaster-error-text-color: #f5f5f5;
+
+						--leptoaster-loading-background-color: #333333;
+						--leptoaster-loading-border-color: #555555;
+						--leptoaster-loading-text-color: #f5f5f5;
+					}
+				}
+				"
+			</style>
+		</Show>
+
+		{variable_overrides.map(|variable_overrides| view! {
+			<style>
+				{variable_overrides}
+			</style>
+		})}
+
+		{match target {
+			Some(target) => view! {
+				<Portal mount={target}>
+					{containers}
+				</Portal>
+			}.into_view(),
+
+			None => containers.into_view(),
+		}}
+	}
+}
+
+/// The minimized toasts of a container. The chips are rendered on the side of the
+/// container furthest from the screen edge so they don't affect the stacking order.
+#[component]
+fn ToastChips(
+	position: &'static ToastPosition,
+	queue: Signal<Vec<ToastData>>,
+) -> impl IntoView {
+	view! {
+		<span
+			style:display="flex"
+			style:flex-wrap="wrap"
+		>
+			<For
+				each=move || {
+					queue.get().iter()
+						.filter(|toast| toast.position.eq(position) && toast.minimized.get()).cloned()
+						.collect::<Vec<ToastData>>()
+				}
+				key=|toast| toast.id
+				let:toast
 			>
-				<div
-					class=get_container_class(stacked(), position)
-					style:width="var(--leptoaster-width)"
-					style:max-width="var(--leptoaster-max-width)"
-					style:margin=get_container_margin(position)
-					style:position="fixed"
-					style:inset=get_container_inset(position)
-					style:z-index="var(--leptoaster-z-index)"
-				>
-					<For
-						each=move || {
-							let toasts = toaster.queue.get();
-
-							match position {
-								ToastPosition::BottomLeft | ToastPosition::BottomRight => {
-									toasts.iter()
-										.filter(|toast| toast.position.eq(position)).cloned()
-										.collect::<Vec<ToastData>>()
-								},
-
-								ToastPosition::TopLeft | ToastPosition::TopRight => {
-									toasts.iter()
-										.filter(|toast| toast.position.eq(position)).cloned()
-										.rev()
-										.collect::<Vec<ToastData>>()
-								},
-							}
-						}
-						key=|toast| toast.id
-						let:toast
-					>
-						<Toast toast={toast} />
-					</For>
-				</div>
-			</Show>
-		</For>
+				<ToastChip toast={toast} />
+			</For>
+		</span>
 	}
 }
 
@@ -222,32 +738,293 @@ pub fn provide_toaster() {
 	}
 }
 
+/// Provides a toaster whose toasts use the defaults of the supplied `ToasterConfig`.
+/// Does nothing if a toaster is already provided.
+pub fn provide_toaster_with_config(config: ToasterConfig) {
+	if use_context::<ToasterContext>().is_none() {
+		provide_context(ToasterContext::with_config(config));
+	}
+}
+
 #[must_use]
 pub fn expect_toaster() -> ToasterContext {
 	expect_context::<ToasterContext>()
 }
 
-fn is_container_empty(position: &ToastPosition) -> bool {
-	!expect_toaster().queue
+/// Stores the supplied toaster so it can be retrieved with `global_toaster` outside
+/// of any component, such as from a background task.
+///
+/// The toaster is stored per thread, as the toaster context is not thread-safe, so it
+/// is only available on the thread that stored it. In the browser, this is the main
+/// thread, which is also the only thread that may update the toaster's signals.
+///
+/// # Examples
+/// ```
+/// #[component]
+/// fn App() -> impl IntoView {
+///     provide_toaster();
+///     set_global_toaster(expect_toaster());
+/// }
+/// ```
+pub fn set_global_toaster(toaster: ToasterContext) {
+	GLOBAL_TOASTER.with(|global| *global.borrow_mut() = Some(toaster));
+}
+
+/// Returns the toaster stored with `set_global_toaster`, or `None` if no toaster was
+/// stored on the current thread.
+///
+/// # Examples
+/// ```
+/// async fn sync_in_background() {
+///     if let Some(toaster) = global_toaster() {
+///         toaster.success("Sync complete.");
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn global_toaster() -> Option<ToasterContext> {
+	GLOBAL_TOASTER.with(|global| global.borrow().clone())
+}
+
+/// Returns the `ToastLabels` supplied to the `Toaster`, or the defaults if none
+/// were supplied.
+#[must_use]
+pub fn use_toast_labels() -> ToastLabels {
+	use_context::<ToastLabels>().unwrap_or_default()
+}
+
+fn get_container_toasts(
+	queue: Signal<Vec<ToastData>>,
+	position: &ToastPosition,
+	expanded_groups: Option<RwSignal<Vec<String>>>,
+	newest_on_top: Option<bool>,
+) -> Vec<ToastData> {
+	let toasts = queue.get()
+		.into_iter()
+		.filter(|toast| toast.position.eq(position) && !toast.minimized.get())
+		.collect::<Vec<ToastData>>();
+
+	let visible = toasts.iter()
+		.filter(|toast| !is_collapsed_group_member(&toasts, toast, expanded_groups))
+		.cloned();
+
+	match newest_on_top.unwrap_or(!is_bottom_position(position)) {
+		true => visible.rev().collect::<Vec<ToastData>>(),
+		false => visible.collect::<Vec<ToastData>>(),
+	}
+}
+
+fn is_collapsed_group_member(
+	toasts: &[ToastData],
+	toast: &ToastData,
+	expanded_groups: Option<RwSignal<Vec<String>>>,
+) -> bool {
+	let (Some(expanded_groups), Some(group)) = (expanded_groups, toast.group.as_ref()) else {
+		return false;
+	};
+
+	if expanded_groups.with(|expanded_groups| expanded_groups.contains(group)) {
+		return false;
+	}
+
+	toasts.iter()
+		.rev()
+		.find(|other| other.group.as_ref() == Some(group))
+		.is_some_and(|newest| newest.id != toast.id)
+}
+
+fn get_group_count(
+	queue: Signal<Vec<ToastData>>,
+	position: &ToastPosition,
+	toast_id: ToastId,
+	group: Option<&str>,
+) -> usize {
+	let Some(group) = group else {
+		return 0;
+	};
+
+	let members = queue.with(|queue| {
+		queue.iter()
+			.filter(|toast| toast.position.eq(position) && !toast.minimized.get() && toast.group.as_deref() == Some(group))
+			.map(|toast| toast.id)
+			.collect::<Vec<ToastId>>()
+	});
+
+	match members.last() == Some(&toast_id) {
+		true => members.len(),
+		false => 0,
+	}
+}
+
+fn handle_keyboard_navigation(
+	event: ev::KeyboardEvent,
+	keyboard_focus: RwSignal<Option<ToastId>>,
+	queue: Signal<Vec<ToastData>>,
+	position: &ToastPosition,
+	expanded_groups: Option<RwSignal<Vec<String>>>,
+	newest_on_top: Option<bool>,
+) {
+	let from_toast = event.target()
+		.and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+		.is_some_and(|element| element.has_attribute("data-leptoaster-id"));
+
+	if !from_toast {
+		return;
+	}
+
+	let Some(focused_id) = keyboard_focus.get_untracked() else {
+		return;
+	};
+
+	let toasts = untrack(|| get_container_toasts(queue, position, expanded_groups, newest_on_top))
+		.into_iter()
+		.filter(|toast| !toast.clear_signal.get_untracked())
+		.collect::<Vec<ToastData>>();
+
+	let Some(index) = toasts.iter().position(|toast| toast.id == focused_id) else {
+		return;
+	};
+
+	let next_index = match event.key().as_str() {
+		"ArrowUp" | "ArrowLeft" => index.checked_sub(1),
+		"ArrowDown" | "ArrowRight" => Some(index + 1).filter(|index| *index < toasts.len()),
+
+		"Delete" | "Backspace" => {
+			if !toasts[index].dismissable {
+				return;
+			}
+
+			toasts[index].clear_signal.set(true);
+
+			match index + 1 < toasts.len() {
+				true => Some(index + 1),
+				false => index.checked_sub(1),
+			}
+		},
+
+		_ => return,
+	};
+
+	event.prevent_default();
+
+	let Some(next_toast) = next_index.map(|index| &toasts[index]) else {
+		return;
+	};
+
+	keyboard_focus.set(Some(next_toast.id));
+
+	let Some(container) = event.current_target()
+		.and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+	else {
+		return;
+	};
+
+	let element = container
+		.query_selector(&format!("[data-leptoaster-id=\"{}\"]", next_toast.id))
+		.ok()
+		.flatten()
+		.and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok());
+
+	if let Some(element) = element {
+		let _ = element.focus();
+	}
+}
+
+fn is_container_empty(queue: Signal<Vec<ToastData>>, position: &ToastPosition) -> bool {
+	!queue
 		.get().iter()
 		.any(|toast| toast.position.eq(position))
 }
 
+fn is_position_enabled(position: &ToastPosition, positions: &Option<Vec<ToastPosition>>) -> bool {
+	positions
+		.as_ref()
+		.is_none_or(|positions| positions.contains(position))
+}
+
+fn get_rendered_toasts(
+	queue: &[ToastData],
+	positions: &Option<Vec<ToastPosition>>,
+	fallback: PositionFallback,
+) -> Vec<ToastData> {
+	queue.iter()
+		.filter_map(|toast| {
+			let position = get_rendered_position(&toast.position, positions, fallback)?;
+
+			Some(ToastData {
+				position: position.clone(),
+				..toast.clone()
+			})
+		})
+		.collect()
+}
+
+fn get_rendered_position(
+	position: &ToastPosition,
+	positions: &Option<Vec<ToastPosition>>,
+	fallback: PositionFallback,
+) -> Option<&'static ToastPosition> {
+	let mut candidates = get_nearest_positions(position).iter();
+
+	match fallback {
+		PositionFallback::Nearest => candidates.find(|candidate| is_position_enabled(candidate, positions)),
+		PositionFallback::Drop => candidates.next().filter(|candidate| is_position_enabled(candidate, positions)),
+	}
+}
+
+fn get_nearest_positions(position: &ToastPosition) -> &'static [ToastPosition] {
+	match position {
+		ToastPosition::TopLeft => &[ToastPosition::TopLeft, ToastPosition::TopCenter, ToastPosition::TopRight, ToastPosition::BottomLeft, ToastPosition::BottomCenter, ToastPosition::BottomRight],
+		ToastPosition::TopCenter => &[ToastPosition::TopCenter, ToastPosition::TopLeft, ToastPosition::TopRight, ToastPosition::BottomCenter, ToastPosition::BottomLeft, ToastPosition::BottomRight],
+		ToastPosition::TopRight => &[ToastPosition::TopRight, ToastPosition::TopCenter, ToastPosition::TopLeft, ToastPosition::BottomRight, ToastPosition::BottomCenter, ToastPosition::BottomLeft],
+		ToastPosition::BottomRight => &[ToastPosition::BottomRight, ToastPosition::BottomCenter, ToastPosition::BottomLeft, ToastPosition::TopRight, ToastPosition::TopCenter, ToastPosition::TopLeft],
+		ToastPosition::BottomCenter => &[ToastPosition::BottomCenter, ToastPosition::BottomLeft, ToastPosition::BottomRight, ToastPosition::TopCenter, ToastPosition::TopLeft, ToastPosition::TopRight],
+		ToastPosition::BottomLeft => &[ToastPosition::BottomLeft, ToastPosition::BottomCenter, ToastPosition::BottomRight, ToastPosition::TopLeft, ToastPosition::TopCenter, ToastPosition::TopRight],
+	}
+}
+
+fn is_center_position(position: &ToastPosition) -> bool {
+	matches!(position, ToastPosition::TopCenter | ToastPosition::BottomCenter)
+}
+
+fn get_align_items(align: ToastAlign) -> &'static str {
+	match align {
+		ToastAlign::Start => "flex-start",
+		ToastAlign::Center => "center",
+		ToastAlign::End => "flex-end",
+	}
+}
+
+fn is_bottom_position(position: &ToastPosition) -> bool {
+	matches!(position, ToastPosition::BottomLeft | ToastPosition::BottomCenter | ToastPosition::BottomRight)
+}
+
 fn get_container_id(position: &ToastPosition) -> &'static str {
 	match position {
 		ToastPosition::TopLeft => "top_left",
+		ToastPosition::TopCenter => "top_center",
 		ToastPosition::TopRight => "top_right",
 		ToastPosition::BottomRight => "bottom_right",
+		ToastPosition::BottomCenter => "bottom_center",
 		ToastPosition::BottomLeft => "bottom_left",
 	}
 }
 
-fn get_container_inset(position: &ToastPosition) -> &'static str {
+fn get_container_inset(position: &ToastPosition, top: &str, bottom: &str, x: &str) -> String {
 	match position {
-		ToastPosition::TopLeft => "0 auto auto 0",
-		ToastPosition::TopRight => "0 0 auto auto",
-		ToastPosition::BottomRight => "auto 0 0 auto",
-		ToastPosition::BottomLeft => "auto 0 0 0",
+		ToastPosition::TopLeft => format!("{top} auto auto {x}"),
+		ToastPosition::TopCenter => format!("{top} auto auto 50%"),
+		ToastPosition::TopRight => format!("{top} {x} auto auto"),
+		ToastPosition::BottomRight => format!("auto {x} {bottom} auto"),
+		ToastPosition::BottomCenter => format!("auto auto {bottom} 50%"),
+		ToastPosition::BottomLeft => format!("auto 0 {bottom} {x}"),
+	}
+}
+
+fn get_container_transform(position: &ToastPosition) -> Option<&'static str> {
+	match position {
+		ToastPosition::TopCenter | ToastPosition::BottomCenter => Some("translateX(-50%)"),
+		_ => None,
 	}
 }
 
@@ -255,16 +1032,65 @@ fn get_container_margin(position: &ToastPosition) -> &'static str {
 	match position {
 		ToastPosition::TopLeft | ToastPosition::BottomLeft => "0 0 0 12px",
 		ToastPosition::TopRight | ToastPosition::BottomRight => "0 12px 0 0",
+		ToastPosition::TopCenter | ToastPosition::BottomCenter => "0",
 	}
 }
 
+fn get_variable_overrides(variables: &[(&str, Option<String>)]) -> Option<String> {
+	let overrides = variables.iter()
+		.filter_map(|(name, value)| value.as_ref().map(|value| format!("--leptoaster-{name}: {value};")))
+		.collect::<Vec<String>>();
+
+	match overrides.is_empty() {
+		true => None,
+		false => Some(format!(":where(:root) {{ {} }}", overrides.join(" "))),
+	}
+}
+
+fn get_stack_styles(stack_depth: u8) -> String {
+	let mut styles = String::new();
+
+	for depth in 1..=u32::from(stack_depth) {
+		let offset = depth - 1;
+		let scale = (1.0 - 0.02 * f64::from(offset)).max(0.5);
+
+		styles.push_str(&format!("
+			.leptoaster-stack-container-bottom > div:nth-last-child({depth}),
+			.leptoaster-stack-container-top > div:nth-child({depth}) {{
+				z-index: calc(var(--leptoaster-z-index) - {offset});
+			}}
+		"));
+
+		if offset > 0 {
+			styles.push_str(&format!("
+				.leptoaster-stack-container-bottom > div:nth-last-child({depth}) {{
+					transform: translateY(calc(var(--leptoaster-stack-step) * {offset})) scaleX({scale:.2});
+				}}
+
+				.leptoaster-stack-container-top > div:nth-child({depth}) {{
+					transform: translateY(calc(var(--leptoaster-stack-step) * -{offset})) scaleX({scale:.2});
+				}}
+			"));
+		}
+	}
+
+	styles.push_str(&format!("
+		.leptoaster-stack-container-bottom > div:nth-last-child(n+{}),
+		.leptoaster-stack-container-top > div:nth-child(n+{}) {{
+			opacity: 0;
+		}}
+	", u32::from(stack_depth) + 1, u32::from(stack_depth) + 1));
+
+	styles
+}
+
 fn get_container_class(stacked: bool, position: &ToastPosition) -> Option<&'static str> {
 	if !stacked {
 		return None;
 	}
 
 	match position {
-		ToastPosition::BottomLeft | ToastPosition::BottomRight => Some("leptoaster-stack-container-bottom"),
-		ToastPosition::TopLeft | ToastPosition::TopRight => Some("leptoaster-stack-container-top"),
+		ToastPosition::BottomLeft | ToastPosition::BottomCenter | ToastPosition::BottomRight => Some("leptoaster-stack-container-bottom"),
+		ToastPosition::TopLeft | ToastPosition::TopCenter | ToastPosition::TopRight => Some("leptoaster-stack-container-top"),
 	}
 }
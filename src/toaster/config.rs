@@ -0,0 +1,76 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashMap;
+
+use crate::toast::{ToastLevel, ToastPosition};
+
+/// The defaults applied to every toast of the toaster, along with the limits of its
+/// queue. Any of the toast defaults can still be overridden for a single toast with
+/// the corresponding `ToastBuilder` function.
+///
+/// # Examples
+/// ```
+/// #[component]
+/// fn App() -> impl IntoView {
+///     provide_toaster_with_config(ToasterConfig {
+///         expiry: Some(4_000),
+///         level_expiry: HashMap::from([(ToastLevel::Error, Some(8_000))]),
+///         position: ToastPosition::TopRight,
+///         ..ToasterConfig::default()
+///     });
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ToasterConfig {
+	pub expiry: Option<u32>,
+
+	/// The expiries of toasts of specific levels, such as a longer expiry for errors.
+	/// Toasts of levels missing from the map use the default `expiry`.
+	pub level_expiry: HashMap<ToastLevel, Option<u32>>,
+
+	pub position: ToastPosition,
+	pub dismissable: bool,
+
+	/// Whether toasts show a progress bar. The progress bar is never shown on toasts
+	/// without an expiry.
+	pub progress: bool,
+
+	/// The maximum number of toasts in the queue, including toasts hidden in a stack
+	/// but not toasts that are animating out. `None`, the default, allows any number
+	/// of toasts.
+	pub max_queue: Option<usize>,
+
+	/// What happens to a new toast that would exceed `max_queue`.
+	pub overflow_policy: OverflowPolicy,
+}
+
+/// Defines what happens when a new toast would exceed the maximum size of the queue.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum OverflowPolicy {
+	/// Clears the oldest toasts in the queue to make room for the new toast.
+	#[default]
+	DropOldest,
+
+	/// Ignores the new toast, leaving the queue unchanged. The rejected toast's
+	/// `ToastId` is `0`, which is never assigned to a toast.
+	RejectNew,
+}
+
+impl Default for ToasterConfig {
+	fn default() -> Self {
+		ToasterConfig {
+			expiry: Some(2_500),
+			level_expiry: HashMap::new(),
+			position: ToastPosition::BottomLeft,
+			dismissable: true,
+			progress: true,
+			max_queue: None,
+			overflow_policy: OverflowPolicy::DropOldest,
+		}
+	}
+}
@@ -8,21 +8,35 @@
 use std::{
 	rc::Rc,
 	cell::RefCell,
+	future::Future,
+	fmt,
 };
 
 use leptos::*;
 
-use crate::toast::{
-	ToastBuilder,
-	ToastData,
-	ToastId,
-	ToastLevel,
+use crate::{
+	toaster::{ToastEvent, ToasterConfig, OverflowPolicy},
+	toast::{
+		ToastBuilder,
+		ToastData,
+		ToastId,
+		ToastLevel,
+		ToastPosition,
+	},
 };
 
+type Subscriber = Rc<dyn Fn(ToastEvent)>;
+type Notifier = Rc<dyn Fn(&ToastData)>;
+
 /// The global context of the toaster. You should provide this as a global context
 /// in your root component to allow any component in your application to toast
 /// using the same toast queue.
 ///
+/// Toast ids are only unique within a single context, starting from 1, and `0` is
+/// never assigned to a toast. Separate contexts, such as the toasters of independent
+/// micro-frontends, can be given disjoint ranges of ids with `with_id_start` or
+/// `set_next_id`.
+///
 ///  # Examples
 ///  ```
 ///  #[component]
@@ -30,20 +44,106 @@ use crate::toast::{
 ///      provide_context(ToasterContext::default());
 ///  }
 ///  ```
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct ToasterContext {
 	stats: Rc<RefCell<ToasterStats>>,
+	subscribers: Rc<RefCell<Vec<Subscriber>>>,
+	notifier: Option<Notifier>,
+	config: ToasterConfig,
+	created: RwSignal<u64>,
+	paused: RwSignal<bool>,
 	pub queue: RwSignal<Vec<ToastData>>,
 }
 
 #[derive(Clone, Default, Debug)]
 struct ToasterStats {
-	visible: u32,
-	total: u64,
+	last_id: ToastId,
+	entering: u32,
+	max_visible: Option<usize>,
 }
 
 impl ToasterContext {
+	/// Constructs a new toaster context whose toasts use the defaults of the supplied
+	/// `ToasterConfig`.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn App() -> impl IntoView {
+	///     provide_context(ToasterContext::with_config(ToasterConfig {
+	///         expiry: Some(4_000),
+	///         ..ToasterConfig::default()
+	///     }));
+	/// }
+	/// ```
+	#[must_use]
+	pub fn with_config(config: ToasterConfig) -> Self {
+		ToasterContext {
+			stats: Rc::new(RefCell::new(ToasterStats::default())),
+			subscribers: Rc::new(RefCell::new(Vec::new())),
+			notifier: None,
+			config,
+			created: create_rw_signal(0),
+			paused: create_rw_signal(false),
+			queue: create_rw_signal(Vec::new()),
+		}
+	}
+
+	/// Constructs a new toaster context whose first toast is assigned the supplied
+	/// `ToastId`, such as for deterministic ids in tests or to keep the ids of separate
+	/// contexts from overlapping. Ids run up to `u64::MAX`, after which new toasts are
+	/// rejected, and an id start of `0` starts at `1`.
+	///
+	/// # Examples
+	/// ```
+	/// let toaster = ToasterContext::with_id_start(1 << 32);
+	/// assert_eq!(toaster.peek_next_id(), 1 << 32);
+	/// ```
+	#[must_use]
+	pub fn with_id_start(id_start: ToastId) -> Self {
+		let toaster = ToasterContext::default();
+		toaster.set_next_id(id_start);
+		toaster
+	}
+
+	/// Sets a notifier that is run with every new toast as it is added to the queue,
+	/// such as to play a sound or show a browser notification for important toasts.
+	/// Toasts merged into an existing toast by their deduplication key and toasts
+	/// rejected by the `overflow_policy` do not run the notifier.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn App() -> impl IntoView {
+	///     provide_context(ToasterContext::default().with_notifier(|toast| {
+	///         if toast.level == ToastLevel::Error {
+	///             if let Ok(audio) = web_sys::HtmlAudioElement::new_with_src("/chime.mp3") {
+	///                 let _ = audio.play();
+	///             }
+	///         }
+	///     }));
+	/// }
+	/// ```
+	#[must_use]
+	pub fn with_notifier(mut self, notifier: impl Fn(&ToastData) + 'static) -> Self {
+		self.notifier = Some(Rc::new(notifier));
+		self
+	}
+
 	/// Adds the supplied toast to the toast queue, displaying it onto the screen.
+	/// Returns the `ToastId` assigned to the toast.
+	///
+	/// If a toast with the same deduplication key is on screen, that toast's expiry
+	/// is restarted and its count incremented instead, and its `ToastId` is returned.
+	///
+	/// If the toast's position then holds more toasts than the maximum set with
+	/// `set_max_visible`, the oldest toasts in that position are cleared.
+	///
+	/// If the queue is full, the toast is handled according to the `overflow_policy`
+	/// of the toaster's `ToasterConfig`. A rejected toast is never displayed and
+	/// returns a `ToastId` of `0`, which is never assigned to a toast, so callers can
+	/// tell that it was rejected. Toasts are also rejected once the toaster has
+	/// assigned the largest `ToastId`, `u64::MAX`.
 	///
 	/// # Examples
 	/// ```
@@ -57,15 +157,90 @@ impl ToasterContext {
 	///     );
 	/// }
 	/// ```
-	pub fn toast(&self, builder: ToastBuilder) {
-		let toast = builder.build(self.stats.borrow().total + 1);
+	pub fn toast(&self, builder: ToastBuilder) -> ToastId {
+		if let Some(toast_id) = self.repeat_duplicate(builder.dedup_key()) {
+			return toast_id;
+		}
+
+		self.admit(|toast_id| builder.build_with_config(toast_id, &self.config))
+	}
 
-		let mut queue = self.queue.get_untracked();
-		queue.push(toast);
-		self.queue.set(queue);
+	/// Restarts the on-screen toast with the supplied deduplication key and increments
+	/// its count, returning its `ToastId`. Returns `None` if no such toast is on screen.
+	fn repeat_duplicate(&self, dedup_key: Option<&str>) -> Option<ToastId> {
+		let (toast_id, repeat_count) = dedup_key.and_then(|dedup_key| {
+			self.queue.with_untracked(|queue| {
+				queue.iter()
+					.find(|toast| toast.dedup_key.as_deref() == Some(dedup_key) && !toast.clear_signal.get_untracked())
+					.map(|toast| (toast.id, toast.repeat_count))
+			})
+		})?;
 
-		self.stats.borrow_mut().visible += 1;
-		self.stats.borrow_mut().total += 1;
+		repeat_count.update(|repeat_count| *repeat_count += 1);
+		Some(toast_id)
+	}
+
+	/// Adds the toast built with the next `ToastId` to the queue, applying the
+	/// `overflow_policy`, the notifier, and the maximum number of visible toasts.
+	/// Returns the `ToastId` of the toast, or `0` if the toast was rejected.
+	fn admit(&self, build: impl FnOnce(ToastId) -> ToastData) -> ToastId {
+		let Some(toast_id) = self.next_id() else {
+			return 0;
+		};
+
+		if let Some(max_queue) = self.config.max_queue {
+			let active = self.queue.with_untracked(|queue| {
+				queue.iter()
+					.filter(|toast| !toast.clear_signal.get_untracked())
+					.map(|toast| toast.clear_signal)
+					.collect::<Vec<RwSignal<bool>>>()
+			});
+
+			let overflow = (active.len() + 1).saturating_sub(max_queue);
+
+			if overflow > 0 {
+				match self.config.overflow_policy {
+					OverflowPolicy::DropOldest => {
+						for clear_signal in active.iter().take(overflow) {
+							clear_signal.set(true);
+						}
+					},
+
+					OverflowPolicy::RejectNew => return 0,
+				}
+			}
+		}
+
+		let toast = build(toast_id);
+		let position = toast.position.clone();
+		let level = toast.level.clone();
+
+		if let Some(notifier) = &self.notifier {
+			notifier(&toast);
+		}
+
+		self.queue.update(|queue| queue.push(toast));
+
+		self.stats.borrow_mut().last_id = toast_id;
+		self.created.update(|created| *created += 1);
+
+		self.emit(ToastEvent::Shown(toast_id, level));
+
+		let max_visible = self.stats.borrow().max_visible;
+
+		if let Some(max_visible) = max_visible {
+			self.queue.with_untracked(|queue| {
+				let active = queue.iter()
+					.filter(|toast| toast.position == position && !toast.clear_signal.get_untracked())
+					.collect::<Vec<&ToastData>>();
+
+				for toast in active.iter().take(active.len().saturating_sub(max_visible)) {
+					toast.clear_signal.set(true);
+				}
+			});
+		}
+
+		toast_id
 	}
 
 	/// Quickly display an `info` toast with default parameters. For more customization,
@@ -79,11 +254,11 @@ impl ToasterContext {
 	///     toaster.info("My toast message.");
 	/// }
 	/// ```
-	pub fn info(&self, message: &str) {
+	pub fn info(&self, message: &str) -> ToastId {
 		self.toast(
 			ToastBuilder::new(message)
 				.with_level(ToastLevel::Info)
-		);
+		)
 	}
 
 	/// Quickly display a `success` toast with default parameters. For more customization,
@@ -97,11 +272,11 @@ impl ToasterContext {
 	///     toaster.success("My toast message.");
 	/// }
 	/// ```
-	pub fn success(&self, message: &str) {
+	pub fn success(&self, message: &str) -> ToastId {
 		self.toast(
 			ToastBuilder::new(message)
 				.with_level(ToastLevel::Success)
-		);
+		)
 	}
 
 	/// Quickly display a `warn` toast with default parameters. For more customization,
@@ -115,11 +290,11 @@ impl ToasterContext {
 	///     toaster.warn("My toast message.");
 	/// }
 	/// ```
-	pub fn warn(&self, message: &str) {
+	pub fn warn(&self, message: &str) -> ToastId {
 		self.toast(
 			ToastBuilder::new(message)
 				.with_level(ToastLevel::Warn)
-		);
+		)
 	}
 
 	/// Quickly display an `error` toast with default parameters. For more customization,
@@ -133,14 +308,162 @@ impl ToasterContext {
 	///     toaster.error("My toast message.");
 	/// }
 	/// ```
-	pub fn error(&self, message: &str) {
+	pub fn error(&self, message: &str) -> ToastId {
 		self.toast(
 			ToastBuilder::new(message)
 				.with_level(ToastLevel::Error)
-		);
+		)
+	}
+
+	/// Quickly display a sticky `error` toast that never expires and stays on screen
+	/// until the user acknowledges it with the dismiss button.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     toaster.error_sticky("My toast message.");
+	/// }
+	/// ```
+	pub fn error_sticky(&self, message: &str) -> ToastId {
+		self.toast(ToastBuilder::error_sticky(message))
+	}
+
+	/// Quickly display a `loading` toast that never expires and cannot be dismissed on
+	/// click. Use the returned `ToastId` to update the toast once loading finishes.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     let toast_id = toaster.loading("Uploading…");
+	///
+	///     toaster.update(toast_id, ToastBuilder::new("Upload complete.").with_level(ToastLevel::Success));
+	/// }
+	/// ```
+	pub fn loading(&self, message: &str) -> ToastId {
+		self.toast(ToastBuilder::loading(message))
+	}
+
+	/// Displays a `loading` toast with the supplied message while the supplied future
+	/// runs, then updates the toast to a `success` or `error` toast with the message
+	/// returned by the corresponding function. Returns the `ToastId` of the toast.
+	///
+	/// The future is only spawned in the browser. When rendering on the server, only
+	/// the loading toast is displayed.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     toaster.toast_async(
+	///         "Saving…",
+	///         save_document(),
+	///         |_| "Document saved.".into(),
+	///         |error| format!("Could not save the document: {error}"),
+	///     );
+	/// }
+	/// ```
+	pub fn toast_async<T, E>(
+		&self,
+		loading_message: &str,
+		future: impl Future<Output = Result<T, E>> + 'static,
+		success_message: impl FnOnce(&T) -> String + 'static,
+		error_message: impl FnOnce(&E) -> String + 'static,
+	) -> ToastId {
+		let toast_id = self.loading(loading_message);
+
+		if cfg!(target_arch = "wasm32") {
+			let toaster = self.clone();
+
+			spawn_local(async move {
+				let builder = match future.await {
+					Ok(value) => ToastBuilder::new(&success_message(&value))
+						.with_level(ToastLevel::Success),
+
+					Err(error) => ToastBuilder::new(&error_message(&error))
+						.with_level(ToastLevel::Error),
+				};
+
+				toaster.update(toast_id, builder);
+			});
+		}
+
+		toast_id
+	}
+
+	/// Updates the message, level, dismissability, expiry, and progress bar of the
+	/// toast corresponding with the supplied `ToastId` to those of the supplied toast.
+	/// The toast stays on screen without animating again, and its expiry restarts.
+	/// Does nothing if no toast with the supplied `ToastId` is in the queue.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     let toast_id = toaster.toast(
+	///         ToastBuilder::new("Uploading…")
+	///             .with_expiry(None)
+	///     );
+	///
+	///     toaster.update(
+	///         toast_id,
+	///         ToastBuilder::new("Upload complete.")
+	///             .with_level(ToastLevel::Success)
+	///     );
+	/// }
+	/// ```
+	pub fn update(&self, toast_id: ToastId, builder: ToastBuilder) {
+		let index = self.queue.with_untracked(|queue| {
+			queue.iter().position(|toast| toast.id == toast_id)
+		});
+
+		if let Some(index) = index {
+			let updated = builder.build_with_config(toast_id, &self.config);
+
+			self.queue.update(|queue| {
+				let toast = &mut queue[index];
+
+				toast.message = updated.message;
+				toast.level = updated.level;
+				toast.dismissable = updated.dismissable;
+				toast.expiry = updated.expiry;
+				toast.progress = updated.progress;
+			});
+		}
+	}
+
+	/// Moves the toast corresponding with the supplied `ToastId` to the supplied
+	/// position, keeping its `ToastId` and its place in the queue. Does nothing if no
+	/// toast with the supplied `ToastId` is in the queue.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     let toast_id = toaster.info("My toast message.");
+	///
+	///     toaster.reposition(toast_id, ToastPosition::TopRight);
+	/// }
+	/// ```
+	pub fn reposition(&self, toast_id: ToastId, position: ToastPosition) {
+		let index = self.queue.with_untracked(|queue| {
+			queue.iter().position(|toast| toast.id == toast_id)
+		});
+
+		if let Some(index) = index {
+			self.queue.update(|queue| queue[index].position = position);
+		}
 	}
 
-	/// Clears all currently visible toasts.
+	/// Clears all currently visible toasts, except for sticky toasts.
 	///
 	/// # Examples
 	/// ```
@@ -158,33 +481,875 @@ impl ToasterContext {
 	/// ```
 	pub fn clear(&self) {
 		for toast in &self.queue.get_untracked() {
-			toast.clear_signal.set(true);
+			if !toast.sticky {
+				toast.clear_signal.set(true);
+			}
+		}
+	}
+
+	/// Clears all currently visible toasts in the supplied position.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     toaster.clear_position(ToastPosition::TopRight);
+	/// }
+	/// ```
+	pub fn clear_position(&self, position: ToastPosition) {
+		for toast in &self.queue.get_untracked() {
+			if toast.position == position {
+				toast.clear_signal.set(true);
+			}
 		}
 	}
 
-	/// Removes the toast corresponding with the supplied `ToastId`.
+	/// Clears all currently visible toasts of the supplied level, such as all errors
+	/// once a retry succeeds.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     toaster.clear_level(ToastLevel::Error);
+	/// }
+	/// ```
+	pub fn clear_level(&self, level: ToastLevel) {
+		for toast in &self.queue.get_untracked() {
+			if toast.level == level {
+				toast.clear_signal.set(true);
+			}
+		}
+	}
+
+	/// Adds the supplied toasts, such as toasts deserialized from storage, back to the
+	/// toast queue. Each toast is assigned a new `ToastId` and is added the same way as
+	/// a toast passed to `toast`, so it is merged into an on-screen toast with the same
+	/// deduplication key, handled according to the `overflow_policy`, passed to the
+	/// notifier, and counted towards the maximum number of visible toasts. The ids are
+	/// returned in the order of the supplied toasts, with a `ToastId` of `0` for each
+	/// rejected toast.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     let saved = serde_json::to_string(&toaster.queue.get_untracked()).unwrap();
+	///     let toasts: Vec<ToastData> = serde_json::from_str(&saved).unwrap();
+	///
+	///     toaster.restore(toasts);
+	/// }
+	/// ```
+	#[cfg(feature = "serde")]
+	pub fn restore(&self, toasts: Vec<ToastData>) -> Vec<ToastId> {
+		toasts.into_iter()
+			.map(|mut toast| {
+				if let Some(toast_id) = self.repeat_duplicate(toast.dedup_key.as_deref()) {
+					return toast_id;
+				}
+
+				self.admit(|toast_id| {
+					toast.id = toast_id;
+					toast
+				})
+			})
+			.collect()
+	}
+
+	/// Dismisses the toast corresponding with the supplied `ToastId`, animating it out
+	/// as if it was clicked. Does nothing if no toast with the supplied `ToastId` is
+	/// in the queue.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     let toast_id = toaster.toast(
+	///         ToastBuilder::new("My toast message.")
+	///             .with_expiry(None) // the toast will not self-expire
+	///     );
+	///
+	///     toaster.dismiss(toast_id);
+	/// }
+	/// ```
+	pub fn dismiss(&self, toast_id: ToastId) {
+		self.queue.with_untracked(|queue| {
+			if let Some(toast) = queue.iter().find(|toast| toast.id == toast_id) {
+				toast.clear_signal.set(true);
+			}
+		});
+	}
+
+	/// Replaces the visible toasts with the supplied set of toasts. Toasts whose key
+	/// matches a visible toast are left on screen untouched, while all other visible
+	/// toasts are cleared and all other supplied toasts are added. Toasts without a
+	/// key never match, so they are always cleared or added.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     toaster.replace_all(vec![
+	///         ToastBuilder::new("You are offline.")
+	///             .with_key("offline")
+	///             .with_expiry(None),
+	///     ]);
+	/// }
+	/// ```
+	pub fn replace_all(&self, builders: Vec<ToastBuilder>) {
+		let kept_keys = self.queue.with_untracked(|queue| {
+			let mut kept_keys = Vec::new();
+
+			for toast in queue {
+				if toast.clear_signal.get_untracked() {
+					continue;
+				}
+
+				let key = toast.key.as_deref()
+					.filter(|key| builders.iter().any(|builder| builder.key() == Some(key)));
+
+				match key {
+					Some(key) => kept_keys.push(key.to_owned()),
+					None => toast.clear_signal.set(true),
+				}
+			}
+
+			kept_keys
+		});
+
+		for builder in builders {
+			if builder.key().is_some_and(|key| kept_keys.iter().any(|kept_key| kept_key == key)) {
+				continue;
+			}
+
+			self.toast(builder);
+		}
+	}
+
+	/// Returns all toasts currently in the queue that belong to the supplied group.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     toaster.toast(
+	///         ToastBuilder::new("Uploading file.")
+	///             .with_group("uploads")
+	///     );
+	///
+	///     let uploads = toaster.toasts_with_group("uploads");
+	/// }
+	/// ```
+	#[must_use]
+	pub fn toasts_with_group(&self, group: &str) -> Vec<ToastData> {
+		self.queue.with(|queue| {
+			queue.iter()
+				.filter(|toast| toast.group.as_deref() == Some(group))
+				.cloned()
+				.collect()
+		})
+	}
+
+	/// Clears all currently visible toasts that belong to the supplied group.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     toaster.dismiss_group("uploads");
+	/// }
+	/// ```
+	pub fn dismiss_group(&self, group: &str) {
+		for toast in &self.queue.get_untracked() {
+			if toast.group.as_deref() == Some(group) {
+				toast.clear_signal.set(true);
+			}
+		}
+	}
+
+	/// Returns the `ToastId`s of all toasts in the queue with the supplied tag.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     toaster.toast(
+	///         ToastBuilder::new("Order #1234 shipped.")
+	///             .with_tag("order-1234")
+	///     );
+	///
+	///     let order_toasts = toaster.find_by_tag("order-1234");
+	/// }
+	/// ```
+	#[must_use]
+	pub fn find_by_tag(&self, tag: &str) -> Vec<ToastId> {
+		self.queue.with_untracked(|queue| {
+			queue.iter()
+				.filter(|toast| toast.tag.as_deref() == Some(tag))
+				.map(|toast| toast.id)
+				.collect()
+		})
+	}
+
+	/// Clears all currently visible toasts with the supplied tag.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     toaster.dismiss_by_tag("order-1234");
+	/// }
+	/// ```
+	pub fn dismiss_by_tag(&self, tag: &str) {
+		self.queue.with_untracked(|queue| {
+			for toast in queue {
+				if toast.tag.as_deref() == Some(tag) {
+					toast.clear_signal.set(true);
+				}
+			}
+		});
+	}
+
+	/// Sets the maximum number of toasts visible in each position. When a new toast
+	/// exceeds the maximum, the oldest toasts in its position are cleared. `None`,
+	/// the default, allows any number of toasts.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     toaster.set_max_visible(Some(3));
+	/// }
+	/// ```
+	pub fn set_max_visible(&self, max_visible: Option<usize>) {
+		self.stats.borrow_mut().max_visible = max_visible;
+	}
+
+	/// Pauses the expiry timers and progress bars of all toasts, including toasts
+	/// added while paused, until `resume_all` is called. Toasts paused individually
+	/// stay paused after resuming.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     window_event_listener(ev::blur, {
+	///         let toaster = toaster.clone();
+	///         move |_| toaster.pause_all()
+	///     });
+	///
+	///     window_event_listener(ev::focus, move |_| toaster.resume_all());
+	/// }
+	/// ```
+	pub fn pause_all(&self) {
+		self.paused.set(true);
+	}
+
+	/// Resumes the expiry timers and progress bars of all toasts paused with
+	/// `pause_all`. Each timer continues from where it was paused.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     toaster.pause_all();
+	///     toaster.resume_all();
+	/// }
+	/// ```
+	pub fn resume_all(&self) {
+		self.paused.set(false);
+	}
+
+	/// Returns a signal that is `true` while all toasts are paused with `pause_all`.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     let is_paused = toaster.is_paused();
+	///
+	///     view! {
+	///         <Show when=move || is_paused()>
+	///             <span>"Notifications paused"</span>
+	///         </Show>
+	///     }
+	/// }
+	/// ```
+	#[must_use]
+	pub fn is_paused(&self) -> Signal<bool> {
+		self.paused.into()
+	}
+
+	/// Returns a signal of the number of toasts currently in the queue, including
+	/// toasts that are animating out.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     let visible_count = toaster.visible_count();
+	///
+	///     view! {
+	///         <span>{move || format!("{} notifications", visible_count())}</span>
+	///     }
+	/// }
+	/// ```
+	#[must_use]
+	pub fn visible_count(&self) -> Signal<usize> {
+		let queue = self.queue;
+		Signal::derive(move || queue.with(Vec::len))
+	}
+
+	/// Returns a signal that is `true` while there are no toasts in the queue,
+	/// including toasts that are animating out.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     let is_empty = toaster.is_empty();
+	///
+	///     view! {
+	///         <Show when=move || !is_empty()>
+	///             <div class="backdrop" />
+	///         </Show>
+	///     }
+	/// }
+	/// ```
+	#[must_use]
+	pub fn is_empty(&self) -> Signal<bool> {
+		let queue = self.queue;
+		Signal::derive(move || queue.with(Vec::is_empty))
+	}
+
+	/// Returns a signal of the number of toasts created since the toaster was
+	/// provided. Toasts merged into an existing toast by their deduplication key are
+	/// not counted.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     let total_count = toaster.total_count();
+	/// }
+	/// ```
+	#[must_use]
+	pub fn total_count(&self) -> Signal<u64> {
+		self.created.into()
+	}
+
+	/// Returns a copy of the toasts currently in the queue without tracking the queue,
+	/// for inspecting the toaster in tests. Only available with the `testing`
+	/// feature.
+	///
+	/// # Examples
+	/// ```
+	/// let toaster = ToasterContext::default();
+	/// toaster.error("My toast message.");
+	///
+	/// let errors = toaster.snapshot()
+	///     .into_iter()
+	///     .filter(|toast| toast.level == ToastLevel::Error)
+	///     .count();
+	///
+	/// assert_eq!(errors, 1);
+	/// ```
+	#[cfg(feature = "testing")]
+	#[must_use]
+	pub fn snapshot(&self) -> Vec<ToastData> {
+		self.queue.get_untracked()
+	}
+
+	/// Returns the number of toasts in the queue that have not been cleared, without
+	/// tracking the queue. Toasts that are animating out are not counted. Only
+	/// available with the `testing` feature.
+	///
+	/// # Examples
+	/// ```
+	/// let toaster = ToasterContext::default();
+	/// let toast_id = toaster.info("My toast message.");
+	///
+	/// toaster.dismiss(toast_id);
+	/// assert_eq!(toaster.pending_count(), 0);
+	/// ```
+	#[cfg(feature = "testing")]
+	#[must_use]
+	pub fn pending_count(&self) -> usize {
+		self.queue.with_untracked(|queue| {
+			queue.iter()
+				.filter(|toast| !toast.clear_signal.get_untracked())
+				.count()
+		})
+	}
+
+	/// Returns the `ToastId` that will be assigned to the next toast, or `0` once the
+	/// largest `ToastId`, `u64::MAX`, has been assigned and new toasts are rejected.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     let next_id = toaster.peek_next_id();
+	/// }
+	/// ```
+	#[must_use]
+	pub fn peek_next_id(&self) -> ToastId {
+		self.next_id().unwrap_or(0)
+	}
+
+	/// Sets the `ToastId` that will be assigned to the next toast. The id is never
+	/// set at or below the id of a toast that is currently in the queue, in which
+	/// case the next id follows the largest queued id instead. Ids run up to
+	/// `u64::MAX`, after which new toasts are rejected, and an id of `0` is raised
+	/// to `1`.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///     toaster.set_next_id(1_000); // the next toast will have an id of `1000`.
+	/// }
+	/// ```
+	pub fn set_next_id(&self, toast_id: ToastId) {
+		let max_queued_id = self.queue.with_untracked(|queue| {
+			queue.iter()
+				.map(|toast| toast.id)
+				.max()
+				.unwrap_or(0)
+		});
+
+		self.stats.borrow_mut().last_id = toast_id.saturating_sub(1).max(max_queued_id);
+	}
+
+	/// Returns the `ToastId` that follows the last assigned id, or `None` if the last
+	/// assigned id is the largest `ToastId`.
+	fn next_id(&self) -> Option<ToastId> {
+		self.stats.borrow().last_id.checked_add(1)
+	}
+
+	/// Registers the start of a toast's enter animation, returning `false` if the
+	/// supplied limit of simultaneous enter animations is already reached, in which
+	/// case the toast should appear without animating.
+	pub(crate) fn start_enter_animation(&self, limit: Option<u32>) -> bool {
+		let mut stats = self.stats.borrow_mut();
+
+		if limit.is_some_and(|limit| stats.entering >= limit) {
+			return false;
+		}
+
+		stats.entering += 1;
+		true
+	}
+
+	/// Registers the end of a toast's enter animation.
+	pub(crate) fn finish_enter_animation(&self) {
+		let mut stats = self.stats.borrow_mut();
+		stats.entering = stats.entering.saturating_sub(1);
+	}
+
+	/// Removes the toast corresponding with the supplied `ToastId` immediately, without
+	/// an exit animation, running its `on_dismiss` callback. To animate the toast out,
+	/// use `dismiss` instead. Removing a toast that is no longer in the queue does
+	/// nothing, so the callback runs at most once per toast.
 	pub fn remove(&self, toast_id: ToastId) {
-		let index = self.queue
-			.get_untracked()
-			.iter().enumerate()
-			.find(|(_, toast)| toast.id == toast_id)
-			.map(|(index, _)| index);
+		let index = self.queue.with_untracked(|queue| {
+			queue.iter().position(|toast| toast.id == toast_id)
+		});
 
-		if let Some(index) = index {
-			let mut queue = self.queue.get_untracked();
-			queue.remove(index);
-			self.queue.set(queue);
+		let removed = index.and_then(|index| self.queue.try_update(|queue| queue.remove(index)));
+
+		if let Some(toast) = removed {
+			if let Some(on_dismiss) = toast.on_dismiss {
+				on_dismiss(toast_id);
+			}
+
+			self.emit(ToastEvent::Dismissed(toast_id));
+		}
+	}
+
+	/// Registers the supplied callback to be run with a `ToastEvent` whenever any
+	/// toast is shown or dismissed, such as for analytics. Unlike a toast's
+	/// `on_dismiss` callback, subscribers observe every toast of the toaster and stay
+	/// registered for the lifetime of the context.
+	///
+	/// The context is shared with `Rc` and is not thread-safe, so subscribers are not
+	/// required to be `Send` and always run on the thread that shows or dismisses the
+	/// toast. Subscribers may themselves show or dismiss toasts.
+	///
+	/// # Examples
+	/// ```
+	/// #[component]
+	/// fn Component() -> impl IntoView {
+	///     let toaster = expect_context::<ToasterContext>();
+	///
+	///     toaster.subscribe(|event| match event {
+	///         ToastEvent::Shown(toast_id, level) => track_shown(toast_id, level),
+	///         ToastEvent::Dismissed(toast_id) => track_dismissed(toast_id),
+	///     });
+	/// }
+	/// ```
+	pub fn subscribe(&self, subscriber: impl Fn(ToastEvent) + 'static) {
+		self.subscribers.borrow_mut().push(Rc::new(subscriber));
+	}
+
+	fn emit(&self, event: ToastEvent) {
+		let subscribers = self.subscribers.borrow().clone();
 
-			self.stats.borrow_mut().visible -= 1;
+		for subscriber in subscribers {
+			subscriber(event.clone());
 		}
 	}
 }
 
+impl fmt::Debug for ToasterContext {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ToasterContext")
+			.field("stats", &self.stats)
+			.field("config", &self.config)
+			.field("created", &self.created)
+			.field("paused", &self.paused)
+			.field("queue", &self.queue)
+			.finish_non_exhaustive()
+	}
+}
+
 impl Default for ToasterContext {
 	fn default() -> Self {
-		ToasterContext {
-			stats: Rc::new(RefCell::new(ToasterStats::default())),
-			queue: create_rw_signal(Vec::new()),
-		}
+		ToasterContext::with_config(ToasterConfig::default())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{rc::Rc, cell::Cell};
+	use leptos::*;
+	use crate::toast::{ToastBuilder, ToastLevel, ToastPosition};
+	use crate::toaster::{ToasterConfig, OverflowPolicy};
+	use super::ToasterContext;
+
+	fn with_runtime(test: impl FnOnce()) {
+		let runtime = create_runtime();
+		test();
+		runtime.dispose();
+	}
+
+	fn counting_toast(dismissals: &Rc<Cell<u32>>) -> ToastBuilder {
+		let dismissals = dismissals.clone();
+
+		ToastBuilder::new("My toast message.")
+			.with_on_dismiss(move |_| dismissals.set(dismissals.get() + 1))
+	}
+
+	#[test]
+	fn on_dismiss_runs_once_when_removed() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			let dismissals = Rc::new(Cell::new(0));
+
+			let toast_id = toaster.toast(counting_toast(&dismissals));
+			toaster.remove(toast_id);
+			toaster.remove(toast_id);
+
+			assert_eq!(dismissals.get(), 1);
+		});
+	}
+
+	#[test]
+	fn on_dismiss_runs_once_when_dismissed() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			let dismissals = Rc::new(Cell::new(0));
+
+			let toast_id = toaster.toast(counting_toast(&dismissals));
+			toaster.dismiss(toast_id);
+			assert_eq!(dismissals.get(), 0);
+
+			toaster.remove(toast_id);
+			toaster.dismiss(toast_id);
+			toaster.remove(toast_id);
+
+			assert_eq!(dismissals.get(), 1);
+		});
+	}
+
+	#[test]
+	fn on_dismiss_runs_once_when_cleared() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			let dismissals = Rc::new(Cell::new(0));
+
+			let toast_id = toaster.toast(counting_toast(&dismissals));
+			toaster.clear();
+			assert_eq!(dismissals.get(), 0);
+
+			toaster.remove(toast_id);
+			toaster.clear();
+
+			assert_eq!(dismissals.get(), 1);
+		});
+	}
+
+	#[test]
+	fn update_keeps_queue_length_and_id() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			toaster.info("First toast.");
+			let toast_id = toaster.info("Uploading…");
+
+			toaster.update(
+				toast_id,
+				ToastBuilder::new("Upload complete.")
+					.with_level(ToastLevel::Success)
+			);
+
+			toaster.queue.with_untracked(|queue| {
+				assert_eq!(queue.len(), 2);
+				assert_eq!(queue[1].id, toast_id);
+				assert_eq!(queue[1].message, "Upload complete.");
+				assert_eq!(queue[1].level, ToastLevel::Success);
+			});
+		});
+	}
+
+	#[test]
+	fn clear_level_keeps_other_levels() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			toaster.info("Info toast.");
+			toaster.error("Error toast.");
+
+			toaster.clear_level(ToastLevel::Error);
+
+			toaster.queue.with_untracked(|queue| {
+				assert_eq!(queue.len(), 2);
+				assert!(!queue[0].clear_signal.get_untracked());
+				assert!(queue[1].clear_signal.get_untracked());
+			});
+		});
+	}
+
+	#[test]
+	fn clear_position_keeps_other_positions() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+
+			toaster.toast(
+				ToastBuilder::new("Top toast.")
+					.with_position(ToastPosition::TopRight)
+			);
+
+			toaster.toast(
+				ToastBuilder::new("Bottom toast.")
+					.with_position(ToastPosition::BottomLeft)
+			);
+
+			toaster.clear_position(ToastPosition::TopRight);
+
+			toaster.queue.with_untracked(|queue| {
+				assert_eq!(queue.len(), 2);
+				assert!(queue[0].clear_signal.get_untracked());
+				assert!(!queue[1].clear_signal.get_untracked());
+			});
+		});
+	}
+
+	#[test]
+	fn clear_keeps_sticky_toasts() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			toaster.info("Regular toast.");
+
+			toaster.toast(
+				ToastBuilder::new("Sticky toast.")
+					.with_sticky(true)
+			);
+
+			toaster.clear();
+
+			toaster.queue.with_untracked(|queue| {
+				assert!(queue[0].clear_signal.get_untracked());
+				assert!(!queue[1].clear_signal.get_untracked());
+			});
+		});
+	}
+
+	#[test]
+	fn removing_twice_does_not_underflow() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			let toast_id = toaster.info("My toast message.");
+
+			toaster.remove(toast_id);
+			toaster.remove(toast_id);
+
+			assert_eq!(toaster.visible_count().get_untracked(), 0);
+			assert_eq!(toaster.total_count().get_untracked(), 1);
+			toaster.queue.with_untracked(|queue| assert!(queue.is_empty()));
+		});
+	}
+
+	#[test]
+	fn drop_oldest_clears_the_oldest_toasts() {
+		with_runtime(|| {
+			let toaster = ToasterContext::with_config(ToasterConfig {
+				max_queue: Some(2),
+				overflow_policy: OverflowPolicy::DropOldest,
+				..ToasterConfig::default()
+			});
+
+			toaster.info("First toast.");
+			toaster.info("Second toast.");
+			let toast_id = toaster.info("Third toast.");
+
+			assert_eq!(toast_id, 3);
+
+			toaster.queue.with_untracked(|queue| {
+				assert_eq!(queue.len(), 3);
+				assert!(queue[0].clear_signal.get_untracked());
+				assert!(!queue[1].clear_signal.get_untracked());
+				assert!(!queue[2].clear_signal.get_untracked());
+			});
+		});
+	}
+
+	#[test]
+	fn reject_new_ignores_new_toasts() {
+		with_runtime(|| {
+			let toaster = ToasterContext::with_config(ToasterConfig {
+				max_queue: Some(2),
+				overflow_policy: OverflowPolicy::RejectNew,
+				..ToasterConfig::default()
+			});
+
+			toaster.info("First toast.");
+			toaster.info("Second toast.");
+
+			assert_eq!(toaster.info("Third toast."), 0);
+			assert_eq!(toaster.peek_next_id(), 3);
+
+			toaster.queue.with_untracked(|queue| {
+				assert_eq!(queue.len(), 2);
+				assert!(queue.iter().all(|toast| !toast.clear_signal.get_untracked()));
+			});
+		});
+	}
+
+	#[test]
+	fn tags_find_and_dismiss_matching_toasts() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+
+			let first_id = toaster.toast(
+				ToastBuilder::new("Order #1234 shipped.")
+					.with_tag("order-1234")
+			);
+
+			toaster.toast(
+				ToastBuilder::new("Order #5678 shipped.")
+					.with_tag("order-5678")
+			);
+
+			let second_id = toaster.toast(
+				ToastBuilder::new("Order #1234 delivered.")
+					.with_tag("order-1234")
+			);
+
+			assert_eq!(toaster.find_by_tag("order-1234"), vec![first_id, second_id]);
+			assert!(toaster.find_by_tag("order-0000").is_empty());
+
+			toaster.dismiss_by_tag("order-1234");
+
+			toaster.queue.with_untracked(|queue| {
+				assert!(queue[0].clear_signal.get_untracked());
+				assert!(!queue[1].clear_signal.get_untracked());
+				assert!(queue[2].clear_signal.get_untracked());
+			});
+		});
+	}
+
+	#[test]
+	fn reposition_keeps_id_and_queue_slot() {
+		with_runtime(|| {
+			let toaster = ToasterContext::default();
+			let first_id = toaster.info("My first toast.");
+			let second_id = toaster.info("My second toast.");
+
+			toaster.reposition(first_id, ToastPosition::TopLeft);
+
+			let queue = toaster.queue.get_untracked();
+			assert_eq!(queue.len(), 2);
+			assert_eq!(queue[0].id, first_id);
+			assert_eq!(queue[0].position, ToastPosition::TopLeft);
+			assert_eq!(queue[1].id, second_id);
+			assert_ne!(queue[1].position, ToastPosition::TopLeft);
+		});
+	}
+
+	#[test]
+	fn ids_stop_at_the_largest_id() {
+		with_runtime(|| {
+			let toaster = ToasterContext::with_id_start(u64::MAX);
+			assert_eq!(toaster.peek_next_id(), u64::MAX);
+
+			assert_eq!(toaster.info("My first toast."), u64::MAX);
+			assert_eq!(toaster.peek_next_id(), 0);
+
+			assert_eq!(toaster.info("My second toast."), 0);
+			assert_eq!(toaster.visible_count().get_untracked(), 1);
+
+			toaster.set_next_id(u64::MAX);
+			assert_eq!(toaster.peek_next_id(), 0);
+		});
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn restore_admits_toasts_like_new_toasts() {
+		with_runtime(|| {
+			let notified = Rc::new(Cell::new(0));
+
+			let toaster = ToasterContext::with_config(ToasterConfig {
+				max_queue: Some(2),
+				overflow_policy: OverflowPolicy::RejectNew,
+				..ToasterConfig::default()
+			}).with_notifier({
+				let notified = notified.clone();
+				move |_| notified.set(notified.get() + 1)
+			});
+
+			let toasts = ["First toast.", "Second toast.", "Third toast."]
+				.into_iter()
+				.map(|message| ToastBuilder::new(message).build(0))
+				.collect();
+
+			assert_eq!(toaster.restore(toasts), vec![1, 2, 0]);
+			assert_eq!(notified.get(), 2);
+			toaster.queue.with_untracked(|queue| assert_eq!(queue.len(), 2));
+		});
 	}
 }
@@ -0,0 +1,19 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::toast::{ToastId, ToastLevel};
+
+/// A lifecycle event of a toast, passed to the subscribers registered with
+/// `ToasterContext::subscribe`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ToastEvent {
+	/// A toast with the supplied id and level was added to the toast queue.
+	Shown(ToastId, ToastLevel),
+
+	/// The toast with the supplied id was removed from the toast queue.
+	Dismissed(ToastId),
+}
@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/// The user-facing strings rendered by the toaster. Override any of these to
+/// localize the built-in toast UI.
+///
+/// # Examples
+/// ```
+/// #[component]
+/// fn App() -> impl IntoView {
+///     let labels = ToastLabels {
+///         dismiss: "Fermer".into(),
+///         ..ToastLabels::default()
+///     };
+///
+///     view! {
+///         <Toaster labels={labels} />
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ToastLabels {
+	pub region: String,
+
+	pub dismiss: String,
+	pub minimize: String,
+	pub expand: String,
+
+	pub show_more: String,
+	pub show_less: String,
+
+	/// The text of the toggle of a collapsed group, where `{count}` is replaced with
+	/// the number of toasts in the group and `{group}` with the name of the group.
+	pub group_count: String,
+}
+
+impl Default for ToastLabels {
+	fn default() -> Self {
+		ToastLabels {
+			region: "Notifications".into(),
+
+			dismiss: "Dismiss".into(),
+			minimize: "Minimize".into(),
+			expand: "Expand".into(),
+
+			show_more: "Show more".into(),
+			show_less: "Show less".into(),
+
+			group_count: "{count} {group}".into(),
+		}
+	}
+}